@@ -0,0 +1,75 @@
+//! Heap usage of the solutions, counted by [`Counting`] once a binary installs it:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: aoc_common::allocations::Counting = aoc_common::allocations::Counting;
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static BASELINE: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, keeping track of the bytes in use and their peak.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn grow(size: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    let now = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(now, Ordering::Relaxed);
+}
+
+/// Starts a new measurement from the bytes in use right now.
+pub fn reset_peak() {
+    let now = CURRENT.load(Ordering::Relaxed);
+    BASELINE.store(now, Ordering::Relaxed);
+    PEAK.store(now, Ordering::Relaxed);
+}
+
+/// Most bytes allocated on top of the baseline since [`reset_peak`],
+/// or `None` when [`Counting`] is not the global allocator.
+pub fn peak() -> Option<usize> {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        return None;
+    }
+
+    Some(
+        PEAK.load(Ordering::Relaxed)
+            .saturating_sub(BASELINE.load(Ordering::Relaxed)),
+    )
+}
@@ -0,0 +1,76 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The value a part produces: a number for every day so far, but text is allowed too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(number: $type) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    /// Numbers too large for an `i128` are kept as their digits.
+    fn from(number: u128) -> Self {
+        i128::try_from(number).map_or_else(|_| Answer::Text(number.to_string()), Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_integers_as_numbers() {
+        assert_eq!(Answer::from(-7i16), Answer::Number(-7));
+        assert_eq!(
+            Answer::from(21156911906816u128),
+            Answer::Number(21156911906816)
+        );
+    }
+
+    #[test]
+    fn keeps_huge_numbers_as_text() {
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn displays_the_bare_value() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
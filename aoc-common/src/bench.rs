@@ -0,0 +1,43 @@
+//! Criterion helpers shared by every day's `benches/solution.rs`.
+
+use crate::{Rng, Solution};
+use criterion::{black_box, BenchmarkId, Criterion};
+use std::path::Path;
+
+/// Reads an input for benchmarking, panicking with the path when it is missing.
+pub fn read(path: impl AsRef<Path>) -> String {
+    let path = path.as_ref();
+    std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+/// Input of about `size` records from the day's generator, always with the same seed
+/// so that every run measures the same input.
+pub fn generated<S: Solution>(size: usize) -> String {
+    S::generate(&mut Rng::new(2020), size)
+        .unwrap_or_else(|| panic!("day {} has no input generator", S::DAY))
+}
+
+/// Benchmarks `parse`, `part1` and `part2` of a day on every named input.
+/// Each function becomes `day-N/<function>/<input name>` in the criterion report.
+pub fn solution<S: Solution>(c: &mut Criterion, inputs: &[(&str, String)]) {
+    let mut group = c.benchmark_group(format!("day-{}", S::DAY));
+    group.sample_size(10);
+
+    for (name, input) in inputs {
+        let input = crate::normalize(input);
+        let parsed =
+            S::parse(&input).unwrap_or_else(|e| panic!("{} input does not parse: {}", name, e));
+
+        group.bench_with_input(BenchmarkId::new("parse", name), &input, |b, input| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part1(black_box(parsed)))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part2(black_box(parsed)))
+        });
+    }
+
+    group.finish();
+}
@@ -0,0 +1,158 @@
+//! Differential testing: a slow but obviously right reference against a faster
+//! candidate, on many generated inputs. The first input they disagree on (or that
+//! makes only one of them panic) is shrunk as far as it still shows the disagreement,
+//! and reported.
+
+use crate::Rng;
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Compares `reference` and `candidate` on the inputs generated for seeds `0..cases`,
+/// panicking with the smallest counterexample `shrink` can find.
+pub fn compare<I, O>(
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> I,
+    shrink: impl Fn(&I) -> Vec<I>,
+    reference: impl Fn(&I) -> O,
+    candidate: impl Fn(&I) -> O,
+) where
+    I: Debug,
+    O: PartialEq + Debug,
+{
+    let disagreement = |input: &I| {
+        let expected = outcome(|| reference(input));
+        let actual = outcome(|| candidate(input));
+        let agree = match (&expected, &actual) {
+            (Ok(expected), Ok(actual)) => expected == actual,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+        (!agree).then_some((expected, actual))
+    };
+
+    for seed in 0..cases {
+        let mut input = generate(&mut Rng::new(seed));
+        let mut found = match disagreement(&input) {
+            Some(found) => found,
+            None => continue,
+        };
+
+        while let Some((smaller, smaller_found)) = shrink(&input)
+            .into_iter()
+            .find_map(|smaller| disagreement(&smaller).map(|found| (smaller, found)))
+        {
+            input = smaller;
+            found = smaller_found;
+        }
+
+        panic!(
+            "reference and candidate disagree (seed {})\n  input:     {:?}\n  reference: {}\n  candidate: {}",
+            seed,
+            input,
+            describe(&found.0),
+            describe(&found.1)
+        );
+    }
+}
+
+fn outcome<O>(f: impl FnOnce() -> O) -> Result<O, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|panic| {
+        panic
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default()
+    })
+}
+
+fn describe<O: Debug>(outcome: &Result<O, String>) -> String {
+    match outcome {
+        Ok(output) => format!("{:?}", output),
+        Err(message) => format!("panicked: {}", message),
+    }
+}
+
+/// Smaller versions of `items`: without each half, quarter, ... and single item in turn.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut smaller = vec![];
+    let mut chunk = items.len() / 2;
+
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let end = (start + chunk).min(items.len());
+            smaller.push([&items[..start], &items[end..]].concat());
+        }
+        chunk /= 2;
+    }
+    if items.len() == 1 {
+        smaller.push(vec![]);
+    }
+
+    smaller
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut Rng) -> Vec<i64> {
+        (0..20).map(|_| rng.range(0..=9)).collect()
+    }
+
+    #[test]
+    fn passes_when_both_agree() {
+        compare(
+            50,
+            numbers,
+            |n| shrink_vec(n),
+            |n| n.iter().sum::<i64>(),
+            |n| n.iter().rev().sum::<i64>(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "input:     [7]\n  reference: 7\n  candidate: 0")]
+    fn shrinks_to_a_minimal_counterexample() {
+        compare(
+            50,
+            numbers,
+            |n| shrink_vec(n),
+            |n| n.iter().sum::<i64>(),
+            |n| n.iter().filter(|&&x| x != 7).sum::<i64>(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "candidate: panicked: no sevens")]
+    fn reports_panics_as_disagreements() {
+        compare(
+            50,
+            numbers,
+            |n| shrink_vec(n),
+            |n| n.len(),
+            |n| {
+                assert!(!n.contains(&7), "no sevens");
+                n.len()
+            },
+        );
+    }
+
+    #[test]
+    fn shrinks_by_removing_chunks() {
+        // when
+        let smaller = shrink_vec(&[1, 2, 3, 4]);
+
+        // then
+        assert_eq!(
+            smaller,
+            vec![
+                vec![3, 4],
+                vec![1, 2],
+                vec![2, 3, 4],
+                vec![1, 3, 4],
+                vec![1, 2, 4],
+                vec![1, 2, 3]
+            ]
+        );
+    }
+}
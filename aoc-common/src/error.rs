@@ -0,0 +1,122 @@
+use std::fmt;
+
+/// Failure to parse or solve a day, pointing at the offending part of the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub day: u8,
+    /// 1-based line of the input, when the error comes from a specific line.
+    pub line: Option<usize>,
+    /// 1-based character column within `text`.
+    pub column: Option<usize>,
+    pub text: String,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// An error not tied to any place in the input, e.g. a puzzle without an answer.
+    pub fn new(day: u8, message: impl Into<String>) -> Error {
+        Error {
+            day,
+            line: None,
+            column: None,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// An error at byte `offset` of `source`, which may span several lines (like a passport).
+    /// Line numbers are relative to `source` until the error is placed with [`Error::on_line`].
+    pub fn parse(day: u8, source: &str, offset: usize, message: impl Into<String>) -> Error {
+        let offset = offset.min(source.len());
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        Error {
+            day,
+            line: Some(source[..line_start].matches('\n').count() + 1),
+            column: Some(source[line_start..offset].chars().count() + 1),
+            text: source[line_start..line_end].to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Moves the error to the input line where its source starts.
+    pub fn on_line(mut self, line: usize) -> Error {
+        self.line = Some(self.line.map_or(line, |relative| line + relative - 1));
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if let Some(line) = self.line {
+            write!(f, ", line {}", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, ", column {}", column)?;
+        }
+        write!(f, ": {}", self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, "\n  | {}", self.text)?;
+            if let Some(column) = self.column {
+                write!(f, "\n  | {}^", " ".repeat(column - 1))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_offset_within_a_record() {
+        // given
+        let record = "ecl:gry pid:860033327\nhcl:#fffffd byr";
+
+        // when
+        let error = Error::parse(4, record, 34, "expected `key:value`").on_line(10);
+
+        // then
+        assert_eq!(error.line, Some(11));
+        assert_eq!(error.column, Some(13));
+        assert_eq!(error.text, "hcl:#fffffd byr");
+    }
+
+    #[test]
+    fn points_at_the_offending_column() {
+        // given
+        let error = Error::parse(8, "jmp +x", 4, "invalid argument").on_line(3);
+
+        // when
+        let message = error.to_string();
+
+        // then
+        assert_eq!(
+            message,
+            "day 8, line 3, column 5: invalid argument\n  | jmp +x\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn omits_location_for_solve_errors() {
+        // given
+        let error = Error::new(9, "no invalid number");
+
+        // when
+        let message = error.to_string();
+
+        // then
+        assert_eq!(message, "day 9: no invalid number");
+    }
+}
@@ -0,0 +1,181 @@
+//! In-process fuzzing for the parsers: random mutations of a seed corpus (usually the
+//! lines or records of `data/data.txt`) are fed to a target, which must never panic.
+//! A panicking input is shrunk to the fewest characters that still panic and reported.
+//!
+//! Runs are reproducible; `AOC_FUZZ_ITERATIONS` and `AOC_FUZZ_SEED` make them longer
+//! or different.
+
+use crate::Rng;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+const DEFAULT_ITERATIONS: u64 = 2000;
+
+const INTERESTING: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    "\n\n",
+    "\t",
+    ":",
+    "-",
+    "+",
+    "#",
+    ".",
+    ",",
+    "0",
+    "-0",
+    "+0",
+    "1",
+    "-1",
+    "32767",
+    "-32768",
+    "65536",
+    "2147483648",
+    "99999999999999999999",
+    "é",
+    "\u{feff}",
+    "\u{0}",
+];
+
+fn env_or(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Feeds `target` mutations of `seeds`, panicking with the smallest input that makes it panic.
+pub fn fuzz(seeds: &[&str], target: impl Fn(&str)) {
+    let iterations = env_or("AOC_FUZZ_ITERATIONS", DEFAULT_ITERATIONS);
+    let mut rng = Rng::new(env_or("AOC_FUZZ_SEED", 0));
+    let words = dictionary(seeds);
+    let panics = |input: &str| catch_unwind(AssertUnwindSafe(|| target(input))).is_err();
+
+    for seed in seeds {
+        if panics(seed) {
+            fail(seed, &panics);
+        }
+    }
+
+    for _ in 0..iterations {
+        let mut input = rng.pick(seeds).chars().collect::<Vec<char>>();
+        for _ in 0..rng.range(1..=4) {
+            mutate(&mut rng, &mut input, seeds, &words);
+        }
+
+        let input = input.into_iter().collect::<String>();
+        if panics(&input) {
+            fail(&input, &panics);
+        }
+    }
+}
+
+/// Words of the corpus next to the generally interesting tokens, for insertions.
+fn dictionary<'a>(seeds: &[&'a str]) -> Vec<&'a str> {
+    let mut words = seeds
+        .iter()
+        .flat_map(|seed| seed.split_whitespace())
+        .chain(INTERESTING.iter().copied())
+        .collect::<Vec<_>>();
+    words.sort_unstable();
+    words.dedup();
+    words
+}
+
+fn mutate(rng: &mut Rng, input: &mut Vec<char>, seeds: &[&str], words: &[&str]) {
+    let at = rng.index(input.len() + 1);
+    let end = (at + rng.range(1..=8) as usize).min(input.len());
+
+    match rng.below(6) {
+        0 => {
+            input.drain(at..end);
+        }
+        1 if at < input.len() => input[at] = random_char(rng),
+        2 => {
+            let copy = input[at..end].to_vec();
+            input.splice(at..at, copy);
+        }
+        3 => {
+            let other = rng.pick(seeds).chars().collect::<Vec<_>>();
+            let from = rng.index(other.len() + 1);
+            input.splice(at..end, other[from..].iter().copied());
+        }
+        _ => {
+            input.splice(at..at, rng.pick(words).chars());
+        }
+    }
+}
+
+fn random_char(rng: &mut Rng) -> char {
+    match rng.below(4) {
+        0 => (b' ' + rng.below(95) as u8) as char,
+        1 => (b'0' + rng.below(10) as u8) as char,
+        2 => (b'a' + rng.below(26) as u8) as char,
+        _ => *rng.pick(&['\n', '\r', '\t', 'é', '€', '\u{0}', '\u{feff}']),
+    }
+}
+
+/// Removes ever smaller runs of characters while the input keeps panicking.
+fn fail(input: &str, panics: &impl Fn(&str) -> bool) -> ! {
+    let mut input = input.chars().collect::<Vec<char>>();
+    let mut chunk = input.len().max(1);
+
+    while chunk > 0 {
+        let mut start = 0;
+        while start < input.len() {
+            let end = (start + chunk).min(input.len());
+            let smaller = [&input[..start], &input[end..]].concat();
+
+            if panics(&smaller.iter().collect::<String>()) {
+                input = smaller;
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+
+    panic!(
+        "target panics on {:?}",
+        input.into_iter().collect::<String>()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes_a_target_that_never_panics() {
+        fuzz(&["1-3 a: abcde", "2-9 c: ccccccccc"], |input| {
+            let _ = input.split(':').nth(1).map(str::trim);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "target panics on \"::\"")]
+    fn shrinks_a_panicking_input() {
+        fuzz(&["1-3 a: abcde", "2-9 c: ccccccccc"], |input| {
+            assert!(input.matches(':').count() < 2);
+        });
+    }
+
+    #[test]
+    fn mutations_keep_valid_utf8_and_vary() {
+        // given
+        let seeds = ["nop +0", "acc -99"];
+        let mut rng = Rng::new(3);
+
+        // when
+        let inputs = (0..50)
+            .map(|_| {
+                let mut input = rng.pick(&seeds).chars().collect::<Vec<_>>();
+                mutate(&mut rng, &mut input, &seeds, &dictionary(&seeds));
+                input.into_iter().collect::<String>()
+            })
+            .collect::<std::collections::HashSet<_>>();
+
+        // then
+        assert!(inputs.len() > 25);
+    }
+}
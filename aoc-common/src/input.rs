@@ -0,0 +1,183 @@
+use crate::error::{Error, Result};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Takes the data file path from the first argument, falling back to `default`.
+pub fn input_path(default: impl AsRef<Path>) -> PathBuf {
+    std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default.as_ref().to_owned())
+}
+
+pub fn read_input(default: impl AsRef<Path>) -> std::io::Result<String> {
+    std::fs::read_to_string(input_path(default))
+}
+
+/// Cleans up an input the way editors and browsers tend to mangle it: drops a UTF-8 BOM,
+/// turns CRLF into LF, strips trailing whitespace from every line and removes trailing
+/// blank lines. Leading lines are kept so that error line numbers still match the file.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let mut normalized = input
+        .split('\n')
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    normalized.truncate(normalized.trim_end().len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    normalized
+}
+
+/// Non-empty lines of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.trim().is_empty())
+}
+
+/// Records separated by blank lines, e.g. passports or customs groups.
+pub fn records(input: &str) -> impl Iterator<Item = &str> {
+    numbered_records(input).map(|(_, record)| record)
+}
+
+/// Records along with the 1-based input line each one starts on.
+pub fn numbered_records(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut line = 1;
+
+    input.split("\n\n").filter_map(move |chunk| {
+        let leading = chunk.len() - chunk.trim_start().len();
+        let start = line + chunk[..leading].matches('\n').count();
+        line += chunk.matches('\n').count() + 2;
+
+        let record = chunk.trim();
+        if record.is_empty() {
+            None
+        } else {
+            Some((start, record))
+        }
+    })
+}
+
+/// Parses every non-empty line, placing errors on the line they come from.
+pub fn parse_lines<T>(input: &str, parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse(line).map_err(|e| e.on_line(index + 1)))
+        .collect()
+}
+
+/// Parses every blank-line separated record, placing errors on the line they come from.
+pub fn parse_records<T>(input: &str, parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    numbered_records(input)
+        .map(|(line, record)| parse(record).map_err(|e| e.on_line(line)))
+        .collect()
+}
+
+/// Parses a whole line as a single number.
+pub fn number<T>(day: u8, text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.trim().parse().map_err(|e| {
+        let offset = text.len() - text.trim_start().len();
+        Error::parse(day, text, offset, format!("invalid number ({})", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_windows_line_endings() {
+        // given
+        let input = "\u{feff}ecl:gry pid:860033327\r\nbyr:1937\r\n\r\niyr:2013 \r\n\r\n\r\n";
+
+        // when
+        let result = normalize(input);
+
+        // then
+        assert_eq!(result, "ecl:gry pid:860033327\nbyr:1937\n\niyr:2013\n");
+    }
+
+    #[test]
+    fn keeps_leading_lines_in_place() {
+        // given
+        let input = "\n  \n1721\t\n979";
+
+        // when
+        let result = normalize(input);
+
+        // then
+        assert_eq!(result, "\n\n1721\n979\n");
+    }
+
+    #[test]
+    fn normalizes_blank_input_to_empty() {
+        // when
+        let result = normalize(" \r\n\n");
+
+        // then
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn skips_trailing_newline() {
+        // given
+        let input = "1721\n979\n";
+
+        // when
+        let result = lines(input).collect::<Vec<&str>>();
+
+        // then
+        assert_eq!(result, vec!["1721", "979"]);
+    }
+
+    #[test]
+    fn splits_records_on_blank_lines() {
+        // given
+        let input = "abc\n\na\nb\nc\n\nab\nac\n";
+
+        // when
+        let result = records(input).collect::<Vec<&str>>();
+
+        // then
+        assert_eq!(result, vec!["abc", "a\nb\nc", "ab\nac"]);
+    }
+
+    #[test]
+    fn numbers_records_by_their_first_line() {
+        // given
+        let input = "abc\n\na\nb\nc\n\n\nab\nac\n";
+
+        // when
+        let result = numbered_records(input)
+            .map(|(line, _)| line)
+            .collect::<Vec<usize>>();
+
+        // then
+        assert_eq!(result, vec![1, 3, 8]);
+    }
+
+    #[test]
+    fn reports_the_line_of_an_invalid_number() {
+        // given
+        let input = "1721\n979\n\n36a6\n";
+
+        // when
+        let result = parse_lines(input, |line| number::<i32>(1, line));
+
+        // then
+        let error = result.unwrap_err();
+        assert_eq!(error.line, Some(4));
+        assert_eq!(error.text, "36a6");
+    }
+}
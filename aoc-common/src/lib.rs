@@ -0,0 +1,35 @@
+//! Helpers shared by every day of the Advent of Code 2020 solutions.
+
+pub mod allocations;
+pub mod answer;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod differential;
+pub mod error;
+pub mod fuzz;
+pub mod input;
+pub mod rng;
+pub mod solution;
+
+pub use answer::Answer;
+pub use error::{Error, Result};
+pub use input::{
+    input_path, lines, normalize, number, numbered_records, parse_lines, parse_records, read_input,
+    records,
+};
+pub use rng::Rng;
+pub use solution::{run, Phase, Puzzle, Solution, Solved, Timing};
+
+use std::fmt::Display;
+
+/// Path to the puzzle input bundled with the calling crate.
+#[macro_export]
+macro_rules! data_file {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/data/data.txt")
+    };
+}
+
+pub fn print_answer(part: u8, answer: impl Display) {
+    println!("[part {}] {}", part, answer);
+}
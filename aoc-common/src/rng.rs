@@ -0,0 +1,115 @@
+//! Small seeded random number generator for synthetic inputs. SplitMix64 is not
+//! cryptographic, but it is the same on every platform and never changes, so a seed
+//! always gives the same input.
+
+use std::ops::RangeInclusive;
+
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..bound`, which must not be empty.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        start.wrapping_add(self.below(end.wrapping_sub(start) as u64 + 1) as i64)
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        // given
+        let mut first = Rng::new(42);
+        let mut second = Rng::new(42);
+
+        // when
+        let a = (0..10).map(|_| first.next_u64()).collect::<Vec<_>>();
+        let b = (0..10).map(|_| second.next_u64()).collect::<Vec<_>>();
+
+        // then
+        assert_eq!(a, b);
+        assert_ne!(
+            a,
+            (0..10).map(|_| Rng::new(43).next_u64()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn matches_reference_splitmix64() {
+        // when
+        let first = Rng::new(0).next_u64();
+
+        // then
+        assert_eq!(first, 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn stays_within_ranges() {
+        // given
+        let mut rng = Rng::new(7);
+
+        for _ in 0..1000 {
+            // when
+            let value = rng.range(-3..=3);
+
+            // then
+            assert!((-3..=3).contains(&value));
+        }
+        assert_eq!(rng.range(5..=5), 5);
+    }
+
+    #[test]
+    fn shuffles_a_permutation() {
+        // given
+        let mut items = (0..20).collect::<Vec<_>>();
+
+        // when
+        Rng::new(1).shuffle(&mut items);
+
+        // then
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,251 @@
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::rng::Rng;
+use std::fmt::{self, Display};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A single day of the calendar: how to read its input and answer both parts.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Part of the key of cached answers: bump it whenever a change could alter an answer,
+    /// so that answers of the older solver are not reused.
+    const VERSION: &'static str = "1";
+
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    /// Reads the puzzle input, already cleaned up by [`normalize`](crate::normalize).
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// A random but valid input of about `size` records, always the same for the same
+    /// seed, or `None` for days without a generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Wall time of one phase, and its peak heap usage when counted by
+/// [`allocations::Counting`](crate::allocations::Counting).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub phase: Phase,
+    pub duration: Duration,
+    pub peak_bytes: Option<usize>,
+}
+
+/// Answers together with how long each phase took to produce them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solved {
+    pub answers: Vec<(u8, Answer)>,
+    pub timings: Vec<Timing>,
+}
+
+/// Object safe view of a [`Solution`], so the runner can keep every day in one list
+/// and solve each one on a thread of its own.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn version(&self) -> &'static str;
+
+    /// Answers for the requested part, or for both parts when `part` is `None`.
+    fn solve(&self, input: &str, part: Option<u8>) -> Result<Vec<(u8, Answer)>> {
+        self.measure(input, part).map(|solved| solved.answers)
+    }
+
+    /// Like [`solve`](Puzzle::solve), timing parsing and each part separately.
+    fn measure(&self, input: &str, part: Option<u8>) -> Result<Solved>;
+
+    /// Synthetic input from [`Solution::generate`], seeded with `seed`.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    /// Crate directory slug, e.g. `report-repair` for day 1.
+    fn slug(&self) -> String {
+        self.title().to_lowercase().replace(' ', "-")
+    }
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn version(&self) -> &'static str {
+        S::VERSION
+    }
+
+    fn measure(&self, input: &str, part: Option<u8>) -> Result<Solved> {
+        measure::<S>(input, part)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
+
+fn measure<S: Solution>(input: &str, part: Option<u8>) -> Result<Solved> {
+    let mut timings = vec![];
+    let mut answers = vec![];
+
+    let parsed = timed(Phase::Parse, &mut timings, || {
+        S::parse(&crate::normalize(input))
+    })?;
+
+    if part.is_none_or(|p| p == 1) {
+        let answer = timed(Phase::Part(1), &mut timings, || S::part1(&parsed))?;
+        answers.push((1, answer.into()));
+    }
+    if part.is_none_or(|p| p == 2) {
+        let answer = timed(Phase::Part(2), &mut timings, || S::part2(&parsed))?;
+        answers.push((2, answer.into()));
+    }
+
+    Ok(Solved { answers, timings })
+}
+
+fn timed<T>(phase: Phase, timings: &mut Vec<Timing>, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    crate::allocations::reset_peak();
+    let start = Instant::now();
+    let value = f()?;
+
+    timings.push(Timing {
+        phase,
+        duration: start.elapsed(),
+        peak_bytes: crate::allocations::peak(),
+    });
+    Ok(value)
+}
+
+/// Body of every day's `main`: reads the input and prints both answers,
+/// exiting with a diagnostic when the input cannot be read or solved.
+pub fn run<S: Solution>(default_input: impl AsRef<Path>) {
+    let path = crate::input_path(default_input);
+    let input = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("Cannot read {}: {}", path.display(), e);
+        std::process::exit(1);
+    });
+
+    match measure::<S>(&input, None) {
+        Ok(solved) => solved
+            .answers
+            .into_iter()
+            .for_each(|(part, answer)| crate::print_answer(part, answer)),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubling;
+
+    impl Solution for Doubling {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Report Repair";
+
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = i32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            crate::parse_lines(input, |line| crate::number(Self::DAY, line))
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer2> {
+            Ok(input.iter().sum::<i32>() * 2)
+        }
+    }
+
+    #[test]
+    fn solves_both_parts_by_default() {
+        // when
+        let answers = Doubling.solve("1\n2\n3\n", None).unwrap();
+
+        // then
+        assert_eq!(
+            answers,
+            vec![(1, Answer::Number(6)), (2, Answer::Number(12))]
+        );
+    }
+
+    #[test]
+    fn solves_only_the_requested_part() {
+        // when
+        let answers = Doubling.solve("1\n2\n3\n", Some(2)).unwrap();
+
+        // then
+        assert_eq!(answers, vec![(2, Answer::Number(12))]);
+    }
+
+    #[test]
+    fn normalizes_input_before_parsing() {
+        // when
+        let answers = Doubling
+            .solve("\u{feff}1\r\n2 \r\n3\r\n\r\n", Some(1))
+            .unwrap();
+
+        // then
+        assert_eq!(answers, vec![(1, Answer::Number(6))]);
+    }
+
+    #[test]
+    fn reports_parse_errors_with_their_line() {
+        // when
+        let error = Doubling.solve("1\n2\nthree\n", None).unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.text, "three");
+    }
+
+    #[test]
+    fn derives_crate_slug_from_title() {
+        // when
+        let slug = Doubling.slug();
+
+        // then
+        assert_eq!(slug, "report-repair");
+    }
+
+    #[test]
+    fn times_each_phase_that_runs() {
+        // when
+        let solved = Doubling.measure("1\n2\n3\n", Some(2)).unwrap();
+
+        // then
+        let phases: Vec<_> = solved.timings.iter().map(|t| t.phase).collect();
+        assert_eq!(phases, vec![Phase::Parse, Phase::Part(2)]);
+        assert_eq!(solved.answers, vec![(2, Answer::Number(12))]);
+    }
+}
@@ -0,0 +1,21 @@
+use aoc_common::allocations::{self, Counting};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn measures_peak_heap_since_reset() {
+    // given
+    let kept = vec![0u8; 1 << 20];
+    allocations::reset_peak();
+
+    // when
+    let temporary = vec![0u8; 4096];
+    drop(temporary);
+    let peak = allocations::peak().unwrap();
+
+    // then
+    assert!(peak >= 4096, "peak was {}", peak);
+    assert!(peak < 1 << 20, "peak was {}", peak);
+    drop(kept);
+}
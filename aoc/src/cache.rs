@@ -0,0 +1,208 @@
+//! Answers kept on disk between runs, one file per answer, so slow days are only solved
+//! again when their input or [`Solution::VERSION`](aoc_common::Solution::VERSION) changes.
+
+use crate::output::input_hash;
+use aoc_common::{Answer, Puzzle};
+use std::path::{Path, PathBuf};
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// `target/answers` of the workspace, so `cargo clean` empties it too.
+    pub fn path() -> PathBuf {
+        crate::days::workspace_root().join("target").join("answers")
+    }
+
+    pub fn open(dir: &Path) -> Cache {
+        Cache {
+            dir: dir.to_owned(),
+        }
+    }
+
+    /// Answers for the requested parts, only when every one of them is cached.
+    pub fn get(
+        &self,
+        puzzle: &dyn Puzzle,
+        input: &str,
+        part: Option<u8>,
+    ) -> Option<Vec<(u8, Answer)>> {
+        let hash = input_hash(input);
+
+        parts(part)
+            .iter()
+            .map(|&part| {
+                let cached = std::fs::read_to_string(self.file(puzzle, part, &hash)).ok()?;
+                Some((part, decode(&cached)?))
+            })
+            .collect()
+    }
+
+    pub fn put(
+        &self,
+        puzzle: &dyn Puzzle,
+        input: &str,
+        answers: &[(u8, Answer)],
+    ) -> Result<(), String> {
+        let hash = input_hash(input);
+        std::fs::create_dir_all(&self.dir)
+            .map_err(|e| format!("Cannot create {}: {}", self.dir.display(), e))?;
+
+        for (part, answer) in answers {
+            let path = self.file(puzzle, *part, &hash);
+            std::fs::write(&path, encode(answer))
+                .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+
+    fn file(&self, puzzle: &dyn Puzzle, part: u8, hash: &str) -> PathBuf {
+        self.dir.join(format!(
+            "day-{}-part-{}-v{}-{}",
+            puzzle.day(),
+            part,
+            puzzle.version(),
+            hash
+        ))
+    }
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn encode(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(number) => format!("number {}", number),
+        Answer::Text(text) => format!("text {}", text),
+    }
+}
+
+fn decode(cached: &str) -> Option<Answer> {
+    match cached.split_once(' ')? {
+        ("number", number) => number.parse().ok().map(Answer::Number),
+        ("text", text) => Some(Answer::Text(text.to_owned())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Result, Solution};
+
+    struct Counting;
+
+    impl Solution for Counting {
+        const DAY: u8 = 3;
+        const TITLE: &'static str = "Counting";
+
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Input) -> Result<Self::Answer1> {
+            Ok(*lines)
+        }
+
+        fn part2(lines: &Self::Input) -> Result<Self::Answer2> {
+            Ok(format!("{} lines", lines))
+        }
+    }
+
+    struct Recounting;
+
+    impl Solution for Recounting {
+        const DAY: u8 = 3;
+        const TITLE: &'static str = "Counting";
+        const VERSION: &'static str = "2";
+
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Counting::parse(input)
+        }
+
+        fn part1(lines: &Self::Input) -> Result<Self::Answer1> {
+            Ok(*lines)
+        }
+
+        fn part2(lines: &Self::Input) -> Result<Self::Answer2> {
+            Ok(*lines)
+        }
+    }
+
+    fn cache(name: &str) -> Cache {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        Cache::open(&dir)
+    }
+
+    #[test]
+    fn returns_stored_answers() {
+        // given
+        let cache = cache("stored");
+        let answers = Counting.solve("a\nb\n", None).unwrap();
+
+        // when
+        cache.put(&Counting, "a\nb\n", &answers).unwrap();
+
+        // then
+        assert_eq!(cache.get(&Counting, "a\nb\n", None), Some(answers.clone()));
+        assert_eq!(
+            cache.get(&Counting, "a\nb\n", Some(2)),
+            Some(vec![answers[1].clone()])
+        );
+    }
+
+    #[test]
+    fn misses_until_every_requested_part_is_stored() {
+        // given
+        let cache = cache("partial");
+        let answers = Counting.solve("a\n", Some(1)).unwrap();
+
+        // when
+        cache.put(&Counting, "a\n", &answers).unwrap();
+
+        // then
+        assert_eq!(cache.get(&Counting, "a\n", Some(1)), Some(answers));
+        assert_eq!(cache.get(&Counting, "a\n", None), None);
+    }
+
+    #[test]
+    fn keys_by_normalized_input_and_version() {
+        // given
+        let cache = cache("keys");
+        let answers = Counting.solve("a\nb\n", None).unwrap();
+        cache.put(&Counting, "a\nb\n", &answers).unwrap();
+
+        // then
+        assert!(cache.get(&Counting, "a\r\nb  \r\n", None).is_some());
+        assert_eq!(cache.get(&Counting, "a\nc\n", None), None);
+        assert_eq!(cache.get(&Recounting, "a\nb\n", None), None);
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        for answer in [
+            Answer::Number(-12),
+            Answer::from("EHZRUSPC"),
+            Answer::from("a b"),
+        ]
+        .iter()
+        {
+            assert_eq!(decode(&encode(answer)).as_ref(), Some(answer));
+        }
+        assert_eq!(decode("garbage"), None);
+    }
+}
@@ -0,0 +1,65 @@
+//! HTTP client for adventofcode.com, or whatever `base_url` points at.
+
+use crate::config::Config;
+use std::time::Duration;
+
+const USER_AGENT: &str = "github.com/szymon-solak/aoc-2020 by yoshuro@hotmail.com";
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Client, String> {
+        Ok(Client {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: config.base_url.trim_end_matches('/').to_owned(),
+            session: config.session()?.to_owned(),
+        })
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/2020/day/{}{}", self.base_url, day, path)
+    }
+
+    /// Downloads the personal puzzle input of a day.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let url = self.url(day, "/input");
+
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| describe(&url, e))?
+            .into_string()
+            .map_err(|e| format!("Cannot read response from {}: {}", url, e))
+    }
+
+    /// Posts an answer and returns the HTML page with the verdict.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = self.url(day, "/answer");
+
+        self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| describe(&url, e))?
+            .into_string()
+            .map_err(|e| format!("Cannot read response from {}: {}", url, e))
+    }
+}
+
+fn describe(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(code, response) => {
+            let body = response.into_string().unwrap_or_default();
+            format!("{} responded with {}: {}", url, code, body.trim())
+        }
+        ureq::Error::Transport(transport) => format!("Cannot reach {}: {}", url, transport),
+    }
+}
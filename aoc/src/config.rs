@@ -0,0 +1,135 @@
+//! Settings for talking to adventofcode.com, read from `aoc.toml` at the workspace root
+//! and overridden by the `AOC_SESSION` and `AOC_BASE_URL` environment variables.
+//!
+//! ```toml
+//! session = "53616c7465645f5f..."
+//! base_url = "https://adventofcode.com"
+//! ```
+
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        crate::days::workspace_root().join("aoc.toml")
+    }
+
+    pub fn load() -> Result<Config, String> {
+        let mut config = Config::from_file(&Config::path())?;
+
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    /// Reads a config file, falling back to the defaults when it does not exist.
+    pub fn from_file(path: &Path) -> Result<Config, String> {
+        let mut config = Config::default();
+        if !path.exists() {
+            return Ok(config);
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|e| format!("Invalid {}: {}", path.display(), e))?;
+
+        let text = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(toml::Value::String(value)) => Ok(Some(value.trim().to_owned())),
+            Some(_) => Err(format!(
+                "Invalid {}: {} should be a string",
+                path.display(),
+                key
+            )),
+        };
+
+        config.session = text("session")?;
+        if let Some(base_url) = text("base_url")? {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| {
+                format!(
+                    "No session token, set AOC_SESSION or `session` in {}",
+                    Config::path().display()
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_session_and_base_url() {
+        // given
+        let path = std::env::temp_dir().join("aoc-config-full.toml");
+        std::fs::write(
+            &path,
+            "session = \"abc123\"\nbase_url = \"http://127.0.0.1:1\"\n",
+        )
+        .unwrap();
+
+        // when
+        let config = Config::from_file(&path).unwrap();
+
+        // then
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://127.0.0.1:1");
+    }
+
+    #[test]
+    fn defaults_without_config_file() {
+        // when
+        let config = Config::from_file(Path::new("/nonexistent/aoc.toml")).unwrap();
+
+        // then
+        assert_eq!(config, Config::default());
+        assert!(config.session().is_err());
+    }
+
+    #[test]
+    fn rejects_non_string_session() {
+        // given
+        let path = std::env::temp_dir().join("aoc-config-invalid.toml");
+        std::fs::write(&path, "session = 123\n").unwrap();
+
+        // when
+        let error = Config::from_file(&path).unwrap_err();
+
+        // then
+        assert!(error.contains("session should be a string"));
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_common::Puzzle;
+use std::path::PathBuf;
+
+pub const DAYS: &[&dyn Puzzle] = &[
+    &day_1_report_repair::ReportRepair,
+    &day_2_password_philosophy::PasswordPhilosophy,
+    &day_3_toboggan_trajectory::TobogganTrajectory,
+    &day_4_passport_processing::PassportProcessing,
+    &day_5_binary_boarding::BinaryBoarding,
+    &day_6_custom_customs::CustomCustoms,
+    &day_7_handy_haversacks::HandyHaversacks,
+    &day_8_handheld_halting::HandheldHalting,
+    &day_9_encoding_error::EncodingError,
+    &day_10_adapter_array::AdapterArray,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().find(|p| p.day() == day).copied()
+}
+
+/// Directory of the day's crate, e.g. `<workspace>/day-1-report-repair`.
+pub fn crate_dir(puzzle: &dyn Puzzle) -> PathBuf {
+    workspace_root().join(format!("day-{}-{}", puzzle.day(), puzzle.slug()))
+}
+
+pub fn default_input(puzzle: &dyn Puzzle) -> PathBuf {
+    crate_dir(puzzle).join("data").join("data.txt")
+}
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_day_has_its_crate_directory() {
+        for puzzle in DAYS {
+            // when
+            let dir = crate_dir(*puzzle);
+
+            // then
+            assert!(dir.is_dir(), "missing {:?}", dir);
+        }
+    }
+
+    #[test]
+    fn days_are_registered_in_order() {
+        // when
+        let days = DAYS.iter().map(|p| p.day()).collect::<Vec<u8>>();
+
+        // then
+        assert_eq!(days[0], 1);
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", days);
+    }
+}
@@ -0,0 +1,114 @@
+//! Puzzle input downloads, cached in each day's `data/` directory.
+
+use crate::client::Client;
+use crate::config::Config;
+use std::path::Path;
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Downloads the day's input into `path`, unless the file is already there.
+pub fn fetch_input(config: &Config, day: u8, path: &Path) -> Result<Fetched, String> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = Client::new(config)?.input(day)?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(path, input).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::FakeServer;
+    use std::path::PathBuf;
+
+    fn config(server: &FakeServer) -> Config {
+        Config {
+            session: Some("abc123".to_owned()),
+            base_url: server.url.to_owned(),
+        }
+    }
+
+    fn cache_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-fetch-{}", name))
+            .join("data.txt");
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn downloads_input_with_session_cookie() {
+        // given
+        let server = FakeServer::start(200, "1721\n979\n");
+        let path = cache_path("download");
+
+        // when
+        let fetched = fetch_input(&config(&server), 1, &path).unwrap();
+
+        // then
+        assert_eq!(fetched, Fetched::Downloaded);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1721\n979\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2020/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    }
+
+    #[test]
+    fn never_downloads_a_cached_input_again() {
+        // given
+        let server = FakeServer::start(200, "1721\n979\n");
+        let path = cache_path("cached");
+        fetch_input(&config(&server), 1, &path).unwrap();
+
+        // when
+        let fetched = fetch_input(&config(&server), 1, &path).unwrap();
+
+        // then
+        assert_eq!(fetched, Fetched::Cached);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn reports_server_errors_without_caching() {
+        // given
+        let server = FakeServer::start(400, "Puzzle inputs differ by user.  Please log in.");
+        let path = cache_path("error");
+
+        // when
+        let error = fetch_input(&config(&server), 1, &path).unwrap_err();
+
+        // then
+        assert!(error.contains("400"));
+        assert!(error.contains("Please log in."));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn requires_a_session_token() {
+        // given
+        let server = FakeServer::start(200, "");
+        let path = cache_path("session");
+
+        // when
+        let error = fetch_input(&Config::default(), 1, &path).unwrap_err();
+
+        // then
+        assert!(error.contains("AOC_SESSION"));
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,296 @@
+//! Running one day over many input files, such as other people's inputs or hand-made
+//! edge cases, with a failure in one file never stopping the others.
+
+pub use crate::isolation::Outcome;
+use aoc_common::{Error, Phase, Puzzle, Solved};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Whether `--input` names several files: a directory or a glob pattern.
+pub fn is_many(input: &Path) -> bool {
+    input.is_dir() || input.to_string_lossy().contains(['*', '?', '['].as_ref())
+}
+
+/// Files of a directory, or those matching a glob pattern, in name order.
+pub fn expand(input: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut paths = if input.is_dir() {
+        std::fs::read_dir(input)
+            .map_err(|e| format!("Cannot read {}: {}", input.display(), e))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && !is_hidden(path))
+            .collect::<Vec<_>>()
+    } else {
+        glob::glob(&input.to_string_lossy())
+            .map_err(|e| format!("Invalid pattern {}: {}", input.display(), e))?
+            .filter_map(|path| path.ok())
+            .filter(|path| path.is_file())
+            .collect()
+    };
+
+    if paths.is_empty() {
+        return Err(format!("No input files in {}", input.display()));
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+}
+
+/// Solves one file in [isolation](crate::isolation), so read errors, parse errors, panics
+/// and timeouts all become an [`Outcome`].
+pub fn solve(
+    puzzle: &'static dyn Puzzle,
+    path: &Path,
+    part: Option<u8>,
+    limit: Duration,
+) -> Outcome {
+    match std::fs::read_to_string(path) {
+        Ok(input) => crate::isolation::solve(puzzle, input, part, limit),
+        Err(e) => {
+            let message = format!("cannot read {}: {}", path.display(), e);
+            Outcome::Failed(Error::new(puzzle.day(), message))
+        }
+    }
+}
+
+/// One row per file with the answer and time of every part, or why there are none.
+pub fn matrix(rows: &[(PathBuf, Outcome)]) -> String {
+    let parts = rows
+        .iter()
+        .filter_map(|(_, outcome)| match outcome {
+            Outcome::Solved(solved) => Some(solved.answers.iter().map(|(part, _)| *part)),
+            _ => None,
+        })
+        .flatten()
+        .fold(vec![], |mut parts, part| {
+            if !parts.contains(&part) {
+                parts.push(part);
+            }
+            parts
+        });
+
+    let mut header = vec!["file".to_owned()];
+    for part in &parts {
+        header.push(format!("part {}", part));
+        header.push("time".to_owned());
+    }
+    let solved = rows
+        .iter()
+        .filter_map(|(path, outcome)| match outcome {
+            Outcome::Solved(solved) => Some(cells(path, &parts, solved)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&solved)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    widths[0] = rows
+        .iter()
+        .map(|(path, _)| path.display().to_string().chars().count())
+        .fold(widths[0], usize::max);
+
+    let mut solved = solved.iter();
+    let mut matrix = layout(&header, &widths);
+    for (path, outcome) in rows {
+        let line = match outcome.failure() {
+            None => layout(solved.next().unwrap(), &widths),
+            Some(failure) => layout(
+                &[path.display().to_string(), first_line(&failure).to_owned()],
+                &widths[..1],
+            ),
+        };
+        matrix.push_str(&line);
+    }
+    matrix
+}
+
+fn cells(path: &Path, parts: &[u8], solved: &Solved) -> Vec<String> {
+    let mut row = vec![path.display().to_string()];
+    for part in parts {
+        row.push(
+            solved
+                .answers
+                .iter()
+                .find(|(p, _)| p == part)
+                .map_or("-".to_owned(), |(_, answer)| answer.to_string()),
+        );
+        row.push(
+            solved
+                .timings
+                .iter()
+                .find(|t| t.phase == Phase::Part(*part))
+                .map_or("-".to_owned(), |t| crate::timings::duration(t.duration)),
+        );
+    }
+    row
+}
+
+/// The file name left aligned and every other cell right aligned, as wide as `widths` says.
+fn layout(row: &[String], widths: &[usize]) -> String {
+    let mut line = format!("{:<width$}", row[0], width = widths[0]);
+    for (column, cell) in row.iter().enumerate().skip(1) {
+        let width = widths.get(column).copied().unwrap_or(0);
+        line.push_str(&format!("  {:>width$}", cell, width = width));
+    }
+    line.trim_end().to_owned() + "\n"
+}
+
+fn first_line(message: &str) -> &str {
+    message.lines().next().unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Result, Solution};
+
+    struct Summing;
+
+    impl Solution for Summing {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Summing";
+
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            aoc_common::parse_lines(input, |line| aoc_common::number(Self::DAY, line))
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer2> {
+            if input.is_empty() {
+                return Err(Error::new(Self::DAY, "Nothing to sum"));
+            }
+            Ok(input.iter().map(|n| 100 / n).sum())
+        }
+    }
+
+    fn inputs(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (file, content) in files {
+            std::fs::write(dir.join(file), content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn expands_directories_and_globs_in_name_order() {
+        // given
+        let dir = inputs(
+            "expand",
+            &[
+                ("b.txt", "1"),
+                ("a.txt", "2"),
+                ("c.in", "3"),
+                (".hidden", "4"),
+            ],
+        );
+
+        // when
+        let all = expand(&dir).unwrap();
+        let txt = expand(&dir.join("*.txt")).unwrap();
+
+        // then
+        assert_eq!(
+            all,
+            vec![dir.join("a.txt"), dir.join("b.txt"), dir.join("c.in")]
+        );
+        assert_eq!(txt, vec![dir.join("a.txt"), dir.join("b.txt")]);
+        assert!(is_many(&dir) && is_many(&dir.join("*.txt")));
+        assert!(!is_many(&dir.join("a.txt")));
+        assert!(expand(&dir.join("*.csv")).is_err());
+    }
+
+    #[test]
+    fn reports_errors_and_panics_without_stopping() {
+        // given
+        let dir = inputs(
+            "outcomes",
+            &[
+                ("good.txt", "1\n2\n"),
+                ("bad.txt", "1\nx\n"),
+                ("zero.txt", "0\n"),
+            ],
+        );
+
+        // when
+        let rows = expand(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|path| {
+                let outcome = solve(&Summing, &path, None, Duration::from_secs(10));
+                (path.strip_prefix(&dir).unwrap().to_owned(), outcome)
+            })
+            .collect::<Vec<_>>();
+
+        // then
+        assert!(matches!(rows[0].1, Outcome::Failed(_)));
+        assert!(matches!(rows[1].1, Outcome::Solved(_)));
+        assert!(
+            matches!(&rows[2].1, Outcome::Panicked(message) if message.contains("divide by zero"))
+        );
+    }
+
+    #[test]
+    fn lays_out_answers_and_timings_per_file() {
+        // given
+        let dir = inputs(
+            "matrix",
+            &[("good.txt", "1\n2\n"), ("longer-name.txt", "4\n")],
+        );
+        let mut rows = expand(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|path| {
+                let outcome = solve(&Summing, &path, None, Duration::from_secs(10));
+                (path.strip_prefix(&dir).unwrap().to_owned(), outcome)
+            })
+            .collect::<Vec<_>>();
+        rows.push((
+            PathBuf::from("bad.txt"),
+            Outcome::Failed(Error::parse(1, "x", 0, "expected a number").on_line(2)),
+        ));
+
+        // when
+        let matrix = matrix(&rows);
+
+        // then
+        let lines = matrix.lines().collect::<Vec<_>>();
+        assert!(
+            lines[0].starts_with("file             part 1"),
+            "{}",
+            matrix
+        );
+        assert!(
+            lines[1].starts_with("good.txt              3"),
+            "{}",
+            matrix
+        );
+        assert!(
+            lines[2].starts_with("longer-name.txt       4"),
+            "{}",
+            matrix
+        );
+        assert_eq!(
+            lines[3],
+            "bad.txt          ERROR  day 1, line 2, column 1: expected a number"
+        );
+    }
+}
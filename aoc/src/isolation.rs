@@ -0,0 +1,142 @@
+//! Solving a day on a thread of its own, so that a panic or an endless loop in one solver
+//! is reported while the other days carry on.
+
+use aoc_common::{Error, Puzzle, Solved};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Recursive solvers get more room than the 2 MiB of a default thread.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Solved),
+    Failed(Error),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    /// Why there are no answers, or `None` when there are.
+    pub fn failure(&self) -> Option<String> {
+        match self {
+            Outcome::Solved(_) => None,
+            Outcome::Failed(error) => Some(format!("ERROR  {}", error)),
+            Outcome::Panicked(message) => Some(format!("PANIC  {}", message)),
+            Outcome::TimedOut(limit) => Some(format!(
+                "TIMEOUT  no answer after {}",
+                crate::timings::duration(*limit)
+            )),
+        }
+    }
+}
+
+/// Measures `puzzle` on `input`, giving up after `limit`. A solver that runs out of time
+/// is left running in the background, as threads cannot be stopped from outside.
+pub fn solve(
+    puzzle: &'static dyn Puzzle,
+    input: String,
+    part: Option<u8>,
+    limit: Duration,
+) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(format!("day {}", puzzle.day()))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let outcome = match catch_unwind(AssertUnwindSafe(|| puzzle.measure(&input, part))) {
+                Ok(Ok(solved)) => Outcome::Solved(solved),
+                Ok(Err(error)) => Outcome::Failed(error),
+                Err(panic) => Outcome::Panicked(
+                    panic
+                        .downcast_ref::<&str>()
+                        .map(|message| message.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "panicked".to_owned()),
+                ),
+            };
+            let _ = sender.send(outcome);
+        });
+
+    if let Err(e) = spawned {
+        let message = format!("cannot start a thread: {}", e);
+        return Outcome::Failed(Error::new(puzzle.day(), message));
+    }
+    receiver
+        .recv_timeout(limit)
+        .unwrap_or(Outcome::TimedOut(limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Error, Result, Solution};
+
+    struct Stubborn;
+
+    impl Solution for Stubborn {
+        const DAY: u8 = 9;
+        const TITLE: &'static str = "Stubborn";
+
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            aoc_common::parse_lines(input, |line| aoc_common::number(Self::DAY, line))
+        }
+
+        fn part1(numbers: &Self::Input) -> Result<Self::Answer1> {
+            match numbers.first() {
+                Some(0) => Err(Error::new(Self::DAY, "zero")),
+                Some(1) => panic!("one"),
+                Some(2) => loop {
+                    thread::sleep(Duration::from_millis(10));
+                },
+                _ => Ok(numbers.iter().sum()),
+            }
+        }
+
+        fn part2(numbers: &Self::Input) -> Result<Self::Answer2> {
+            Ok(numbers.len() as u64)
+        }
+    }
+
+    fn solve(input: &str) -> Outcome {
+        super::solve(
+            &Stubborn,
+            input.to_owned(),
+            None,
+            Duration::from_millis(200),
+        )
+    }
+
+    #[test]
+    fn returns_answers_of_a_well_behaved_solver() {
+        // when
+        let outcome = solve("3\n4\n");
+
+        // then
+        match outcome {
+            Outcome::Solved(solved) => assert_eq!(solved.answers.len(), 2),
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(solve("3\n").failure(), None);
+    }
+
+    #[test]
+    fn records_errors_panics_and_timeouts() {
+        // when
+        let failures = ["0\n", "1\n", "2\n"]
+            .iter()
+            .map(|input| solve(input).failure().unwrap())
+            .collect::<Vec<_>>();
+
+        // then
+        assert!(failures[0].starts_with("ERROR  day 9"), "{}", failures[0]);
+        assert_eq!(failures[1], "PANIC  one");
+        assert_eq!(failures[2], "TIMEOUT  no answer after 200.00ms");
+    }
+}
@@ -0,0 +1,19 @@
+//! Runner for every day of the Advent of Code 2020 solutions.
+
+pub mod cache;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod fetch;
+pub mod inputs;
+pub mod isolation;
+pub mod output;
+pub mod scaffold;
+pub mod serve;
+pub mod submit;
+pub mod timings;
+pub mod verify;
+pub mod watch;
+
+#[cfg(test)]
+mod fake_server;
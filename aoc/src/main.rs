@@ -0,0 +1,352 @@
+use aoc::cache::Cache;
+use aoc::client::Client;
+use aoc::config::Config;
+use aoc::fetch::{self, Fetched};
+use aoc::inputs;
+use aoc::isolation::{self, Outcome};
+use aoc::output::{self, Format};
+use aoc::scaffold::{self, Day};
+use aoc::serve;
+use aoc::submit::{self, Log, Response, Verdict};
+use aoc::timings::{self, Row};
+use aoc::{days, verify, watch};
+use aoc_common::allocations::Counting;
+use aoc_common::{Puzzle, Solved, Timing};
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2020 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a single day, or every day with --all
+    Run {
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        #[arg(long)]
+        all: bool,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or a directory or glob of them to solve each and print a matrix
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Prints how long each phase took, slowest day first
+        #[arg(long, conflicts_with = "format")]
+        timings: bool,
+        /// Adds the peak heap usage of each day to the timings
+        #[arg(long, requires = "timings")]
+        allocations: bool,
+        /// Prints JSON lines of {day, part, answer, duration_ns, input_hash, cached} instead
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Solves again even when the answers for this input and solver version are cached
+        #[arg(long)]
+        no_cache: bool,
+        /// Gives up on a day (or input file) that has no answers after this many seconds
+        #[arg(long, value_name = "SECONDS", default_value = "60", value_parser = seconds)]
+        time_limit: Duration,
+    },
+    /// Checks every day (or just one) against the answers recorded in its answers.toml
+    Verify { day: Option<u8> },
+    /// Downloads puzzle inputs into each day's data directory, unless already there
+    Fetch {
+        #[arg(required = true)]
+        days: Vec<u8>,
+    },
+    /// Writes a random but valid input for a day, the same for the same seed
+    Generate {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly the number of records (lines, passports, groups...) to generate
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        /// File to write instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Creates the day-N-slug crate for a new day and registers it with the runner
+    New { day: u8, slug: String },
+    /// Solves one part and posts the answer, unless an earlier guess already rules it out
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Reruns a day's examples and real input whenever its src/ or data/ change
+    Watch { day: u8 },
+    /// Answers POST /2020/day/{n}/part/{p} with the posted input over HTTP
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:2020")]
+        address: String,
+        /// Gives up on a request that has no answer after this many seconds
+        #[arg(long, value_name = "SECONDS", default_value = "60", value_parser = seconds)]
+        time_limit: Duration,
+    },
+}
+
+fn main() {
+    if let Err(message) = execute(Cli::parse()) {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+}
+
+fn execute(cli: Cli) -> Result<(), String> {
+    match cli.command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            timings,
+            allocations,
+            format,
+            no_cache,
+            time_limit,
+        } => {
+            if let Some(pattern) = input.as_deref().filter(|input| inputs::is_many(input)) {
+                if format == Format::Json || timings {
+                    return Err(
+                        "Several inputs print a matrix, without --format or --timings".to_owned(),
+                    );
+                }
+                let puzzle = days::find(day.unwrap())
+                    .ok_or(format!("Day {} is not solved yet", day.unwrap()))?;
+
+                let rows = inputs::expand(pattern)?
+                    .into_iter()
+                    .map(|path| {
+                        let outcome = inputs::solve(puzzle, &path, part, time_limit);
+                        (path, outcome)
+                    })
+                    .collect::<Vec<_>>();
+                println!("Day {}: {}", puzzle.day(), puzzle.title());
+                print!("{}", inputs::matrix(&rows));
+
+                let failures = rows
+                    .iter()
+                    .filter(|(_, outcome)| !matches!(outcome, Outcome::Solved(_)))
+                    .count();
+                if failures > 0 {
+                    return Err(format!("{} of {} input(s) failed", failures, rows.len()));
+                }
+                return Ok(());
+            }
+
+            let puzzles = if all {
+                days::DAYS.to_vec()
+            } else {
+                let day = day.unwrap();
+                vec![days::find(day).ok_or(format!("Day {} is not solved yet", day))?]
+            };
+
+            // timings of cached answers would be meaningless, so measuring always solves
+            let cache = Some(Cache::open(&Cache::path())).filter(|_| !no_cache && !timings);
+            let mut rows = vec![];
+            let mut failures = 0;
+            for puzzle in puzzles {
+                let path = input.clone().unwrap_or_else(|| days::default_input(puzzle));
+                match run(puzzle, &path, part, format, cache.as_ref(), time_limit) {
+                    Ok(timings) => rows.push(Row {
+                        day: puzzle.day(),
+                        title: puzzle.title(),
+                        timings,
+                    }),
+                    Err(message) => {
+                        eprintln!("{}", message);
+                        failures += 1;
+                    }
+                }
+            }
+
+            if timings {
+                println!();
+                print!("{}", timings::table(rows, allocations));
+            }
+            if failures > 0 {
+                return Err(format!("{} day(s) failed", failures));
+            }
+        }
+        Command::Verify { day } => {
+            let (checks, skipped) = match day {
+                Some(day) => {
+                    let puzzle = days::find(day).ok_or(format!("Day {} is not solved yet", day))?;
+                    match verify::not_ready(&days::crate_dir(puzzle)) {
+                        Some(reason) => (vec![], vec![(day, reason)]),
+                        None => (
+                            verify::verify(
+                                puzzle,
+                                &days::default_input(puzzle),
+                                &verify::answers_file(puzzle),
+                            ),
+                            vec![],
+                        ),
+                    }
+                }
+                None => verify::verify_all(),
+            };
+            for (day, reason) in &skipped {
+                eprintln!("Day {}: skipped, {}", day, reason);
+            }
+
+            let failed = checks.iter().filter(|c| !c.passed()).count();
+            if failed > 0 {
+                print!("{}", verify::mismatch_table(&checks));
+                return Err(format!("{} of {} parts do not match", failed, checks.len()));
+            }
+
+            let unknown = checks.iter().filter(|c| c.expected.is_none()).count();
+            println!(
+                "{} parts match their known answers, {} without a known answer",
+                checks.len() - unknown,
+                unknown
+            );
+        }
+        Command::Fetch { days: numbers } => {
+            let config = Config::load()?;
+
+            for day in numbers {
+                let puzzle = days::find(day).ok_or(format!("Day {} is not solved yet", day))?;
+                let path = days::default_input(puzzle);
+
+                match fetch::fetch_input(&config, day, &path)? {
+                    Fetched::Downloaded => println!("Day {}: downloaded {}", day, path.display()),
+                    Fetched::Cached => println!("Day {}: cached at {}", day, path.display()),
+                }
+            }
+        }
+        Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let puzzle = days::find(day).ok_or(format!("Day {} is not solved yet", day))?;
+            let input = puzzle
+                .generate(seed, size)
+                .ok_or(format!("Day {} has no input generator", day))?;
+
+            match output {
+                Some(path) => std::fs::write(&path, input)
+                    .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?,
+                None => print!("{}", input),
+            }
+        }
+        Command::New { day, slug } => {
+            let day = Day::new(day, &slug)?;
+            let dir = scaffold::scaffold(&days::workspace_root(), &day)?;
+
+            println!("Created {}", dir.display());
+            println!(
+                "Next: `cargo run -p aoc -- fetch {}`, then paste the example into src/lib.rs",
+                day.day
+            );
+        }
+        Command::Submit { day, part, input } => {
+            let puzzle = days::find(day).ok_or(format!("Day {} is not solved yet", day))?;
+            let path = input.unwrap_or_else(|| days::default_input(puzzle));
+            let input = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+
+            let (_, answer) = puzzle
+                .solve(&input, Some(part))
+                .map_err(|e| e.to_string())?
+                .remove(0);
+            println!("Day {} part {}: submitting {}", day, part, answer);
+
+            let client = Client::new(&Config::load()?)?;
+            let mut log = Log::open(&Log::path())?;
+            let response = submit::submit(&client, &mut log, day, part, &answer.to_string())?;
+
+            if response != Response::Judged(Verdict::Correct) {
+                return Err(response.to_string());
+            }
+            println!("{}", response);
+        }
+        Command::Watch { day } => {
+            let puzzle = days::find(day).ok_or(format!("Day {} is not solved yet", day))?;
+            watch::watch(puzzle)?;
+        }
+        Command::Serve {
+            address,
+            time_limit,
+        } => {
+            let server = serve::bind(&address)?;
+            println!("Listening on http://{}", server.server_addr());
+            serve::serve(server, time_limit);
+        }
+    }
+
+    Ok(())
+}
+
+fn seconds(value: &str) -> Result<Duration, String> {
+    match value.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Duration::from_secs_f64(seconds)),
+        _ => Err(format!(
+            "expected a positive number of seconds, not {:?}",
+            value
+        )),
+    }
+}
+
+fn run(
+    puzzle: &'static dyn Puzzle,
+    path: &Path,
+    part: Option<u8>,
+    format: Format,
+    cache: Option<&Cache>,
+    limit: Duration,
+) -> Result<Vec<Timing>, String> {
+    let input = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+
+    let cached = cache.and_then(|cache| cache.get(puzzle, &input, part));
+    let solved = match &cached {
+        Some(answers) => Solved {
+            answers: answers.clone(),
+            timings: vec![],
+        },
+        None => match isolation::solve(puzzle, input.clone(), part, limit) {
+            Outcome::Solved(solved) => {
+                if let Some(cache) = cache {
+                    cache.put(puzzle, &input, &solved.answers)?;
+                }
+                solved
+            }
+            Outcome::Failed(error) => return Err(error.to_string()),
+            outcome => {
+                let failure = outcome.failure().unwrap_or_default();
+                return Err(format!("Day {}: {}", puzzle.day(), failure));
+            }
+        },
+    };
+
+    match format {
+        Format::Text => {
+            let note = if cached.is_some() { " (cached)" } else { "" };
+            println!("Day {}: {}{}", puzzle.day(), puzzle.title(), note);
+            for (part, answer) in &solved.answers {
+                aoc_common::print_answer(*part, answer);
+            }
+        }
+        Format::Json => print!(
+            "{}",
+            output::json_lines(puzzle.day(), &input, &solved, cached.is_some())
+        ),
+    }
+
+    Ok(solved.timings)
+}
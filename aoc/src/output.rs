@@ -0,0 +1,118 @@
+//! Answers as JSON lines, one object per solved part, for scripts to consume.
+
+use aoc_common::{Answer, Phase, Solved};
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    #[serde(serialize_with = "answer")]
+    answer: &'a Answer,
+    duration_ns: u128,
+    input_hash: &'a str,
+    cached: bool,
+}
+
+fn answer<S: Serializer>(answer: &&Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::Number(number) => serializer.serialize_i128(*number),
+        Answer::Text(text) => serializer.serialize_str(text),
+    }
+}
+
+/// SHA-256 of the input once [`normalize`](aoc_common::normalize)d, in hex,
+/// so inputs differing only in line endings or trailing whitespace hash the same.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(aoc_common::normalize(input).as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// One line per answer, each with the time its part took, or 0 when it came from the cache.
+pub fn json_lines(day: u8, input: &str, solved: &Solved, cached: bool) -> String {
+    let hash = input_hash(input);
+
+    solved
+        .answers
+        .iter()
+        .map(|(part, answer)| {
+            let duration = solved
+                .timings
+                .iter()
+                .find(|t| t.phase == Phase::Part(*part))
+                .map_or(0, |t| t.duration.as_nanos());
+            let record = Record {
+                day,
+                part: *part,
+                answer,
+                duration_ns: duration,
+                input_hash: &hash,
+                cached,
+            };
+            serde_json::to_string(&record).unwrap() + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Timing;
+    use std::time::Duration;
+
+    fn solved(answers: Vec<(u8, Answer)>) -> Solved {
+        Solved {
+            timings: answers
+                .iter()
+                .map(|(part, _)| Timing {
+                    phase: Phase::Part(*part),
+                    duration: Duration::from_nanos(1500),
+                    peak_bytes: None,
+                })
+                .collect(),
+            answers,
+        }
+    }
+
+    #[test]
+    fn writes_one_object_per_answer() {
+        // given
+        let solved = solved(vec![(1, Answer::Number(514579)), (2, Answer::from("abc"))]);
+
+        // when
+        let output = json_lines(1, "1721\n979\n", &solved, false);
+
+        // then
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["day"], 1);
+        assert_eq!(lines[0]["part"], 1);
+        assert_eq!(lines[0]["answer"], 514579);
+        assert_eq!(lines[0]["duration_ns"], 1500);
+        assert_eq!(lines[0]["input_hash"], input_hash("1721\n979\n"));
+        assert_eq!(lines[0]["cached"], false);
+        assert_eq!(lines[1]["answer"], "abc");
+    }
+
+    #[test]
+    fn hashes_normalized_input() {
+        // when
+        let hash = input_hash("\u{feff}1721\r\n979  \r\n\r\n");
+
+        // then
+        assert_eq!(hash, input_hash("1721\n979\n"));
+        assert_eq!(hash.len(), 64);
+    }
+}
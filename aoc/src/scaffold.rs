@@ -0,0 +1,341 @@
+//! `aoc new`: a fresh `day-N-slug` crate from the templates in `aoc/templates/day`,
+//! registered in the workspace and in the runner's list of days.
+
+use std::path::{Path, PathBuf};
+
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.tmpl"),
+    ),
+    (
+        "answers.toml",
+        include_str!("../templates/day/answers.toml.tmpl"),
+    ),
+    ("src/main.rs", include_str!("../templates/day/main.rs.tmpl")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs.tmpl")),
+    (
+        "benches/solution.rs",
+        include_str!("../templates/day/solution.rs.tmpl"),
+    ),
+];
+
+/// Names a new day goes by, all derived from its number and slug.
+#[derive(Debug, PartialEq)]
+pub struct Day {
+    pub day: u8,
+    /// Crate name, e.g. `day-7-handy-haversacks`.
+    pub krate: String,
+    /// Struct implementing `Solution`, e.g. `HandyHaversacks`.
+    pub name: String,
+    pub title: String,
+}
+
+impl Day {
+    pub fn new(day: u8, slug: &str) -> Result<Day, String> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("Day {} is not in the calendar", day));
+        }
+
+        let words = slug.split('-').collect::<Vec<_>>();
+        let valid = words.iter().all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        }) && slug.starts_with(|c: char| c.is_ascii_lowercase());
+        if !valid {
+            return Err(format!(
+                "Invalid slug `{}`, expected lowercase words separated by dashes, e.g. handy-haversacks",
+                slug
+            ));
+        }
+
+        let capitalized = words
+            .iter()
+            .map(|word| word[..1].to_uppercase() + &word[1..])
+            .collect::<Vec<_>>();
+
+        Ok(Day {
+            day,
+            krate: format!("day-{}-{}", day, slug),
+            name: capitalized.concat(),
+            title: capitalized.join(" "),
+        })
+    }
+
+    fn module(&self) -> String {
+        self.krate.replace('-', "_")
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{crate}", &self.krate)
+            .replace("{module}", &self.module())
+            .replace("{name}", &self.name)
+            .replace("{title}", &self.title)
+            .replace("{day}", &self.day.to_string())
+    }
+}
+
+/// Writes the new crate under `root` and registers it, returning its directory.
+pub fn scaffold(root: &Path, day: &Day) -> Result<PathBuf, String> {
+    let members = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("days.rs");
+
+    let prefix = format!("day-{}-", day.day);
+    let taken = std::fs::read_dir(root)
+        .map_err(|e| format!("Cannot read {}: {}", root.display(), e))?
+        .flatten()
+        .any(|entry| entry.file_name().to_string_lossy().starts_with(&prefix));
+    if taken {
+        return Err(format!("Day {} already has a crate", day.day));
+    }
+
+    // Edit every file in memory first, so a failure leaves the workspace untouched.
+    let members_edit = insert_day_line(
+        &read(&members)?,
+        day.day,
+        &format!("    \"{}\",", day.krate),
+        |line| day_number(line.trim().trim_matches(|c| c == '"' || c == ',')),
+    )?;
+    let manifest_edit = insert_day_line(
+        &read(&runner_manifest)?,
+        day.day,
+        &format!("{} = {{ path = \"../{}\" }}", day.krate, day.krate),
+        |line| day_number(line.split(" = ").next().unwrap_or("")),
+    )?;
+    let registry_edit = insert_day_line(
+        &read(&registry)?,
+        day.day,
+        &format!("    &{}::{},", day.module(), day.name),
+        |line| {
+            let path = line.trim().strip_prefix('&')?;
+            day_number(&path.split("::").next()?.replace('_', "-"))
+        },
+    )?;
+
+    let dir = root.join(&day.krate);
+    for (file, template) in TEMPLATES {
+        write(&dir.join(file), &day.render(template))?;
+    }
+    std::fs::create_dir_all(dir.join("data"))
+        .map_err(|e| format!("Cannot create data directory: {}", e))?;
+
+    write(&members, &members_edit)?;
+    write(&runner_manifest, &manifest_edit)?;
+    write(&registry, &registry_edit)?;
+
+    Ok(dir)
+}
+
+/// Day number of a `day-N-slug` crate name.
+fn day_number(name: &str) -> Option<u8> {
+    name.strip_prefix("day-")?.split('-').next()?.parse().ok()
+}
+
+/// Adds `new_line` among the lines naming other days, keeping them in day order.
+fn insert_day_line(
+    content: &str,
+    day: u8,
+    new_line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| day_of(line).map(|d| (index, d)))
+        .collect::<Vec<_>>();
+
+    let index = match days.iter().find(|(_, d)| *d > day) {
+        Some((index, _)) => *index,
+        None => days.last().ok_or("no other day is registered there")?.0 + 1,
+    };
+
+    let mut edited = lines[..index].join("\n");
+    if index > 0 {
+        edited.push('\n');
+    }
+    edited.push_str(new_line);
+    edited.push('\n');
+    for line in &lines[index..] {
+        edited.push_str(line);
+        edited.push('\n');
+    }
+
+    Ok(edited)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(path, content).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", name));
+        let _ = std::fs::remove_dir_all(&root);
+
+        write(
+            &root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-1-report-repair\",\n    \"day-10-adapter-array\",\n]\n",
+        )
+        .unwrap();
+        write(
+            &root.join("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\nday-1-report-repair = { path = \"../day-1-report-repair\" }\nday-10-adapter-array = { path = \"../day-10-adapter-array\" }\ntoml = \"0.8\"\n",
+        )
+        .unwrap();
+        write(
+            &root.join("aoc/src/days.rs"),
+            "pub const DAYS: &[&dyn Puzzle] = &[\n    &day_1_report_repair::ReportRepair,\n    &day_10_adapter_array::AdapterArray,\n];\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("day-1-report-repair")).unwrap();
+        std::fs::create_dir_all(root.join("day-10-adapter-array")).unwrap();
+
+        root
+    }
+
+    #[test]
+    fn derives_names_from_slug() {
+        // when
+        let day = Day::new(7, "handy-haversacks").unwrap();
+
+        // then
+        assert_eq!(
+            day,
+            Day {
+                day: 7,
+                krate: "day-7-handy-haversacks".to_owned(),
+                name: "HandyHaversacks".to_owned(),
+                title: "Handy Haversacks".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_slugs_and_days() {
+        assert!(Day::new(7, "Handy Haversacks").is_err());
+        assert!(Day::new(7, "handy--haversacks").is_err());
+        assert!(Day::new(7, "7-haversacks").is_err());
+        assert!(Day::new(26, "handy-haversacks").is_err());
+    }
+
+    #[test]
+    fn creates_crate_from_templates() {
+        // given
+        let root = workspace("create");
+
+        // when
+        let dir = scaffold(&root, &Day::new(7, "handy-haversacks").unwrap()).unwrap();
+
+        // then
+        let lib = std::fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(
+            lib.starts_with("//! [Day 7: Handy Haversacks](https://adventofcode.com/2020/day/7)\n")
+        );
+        assert!(lib.contains("pub struct HandyHaversacks;"));
+        assert!(lib.contains("const TITLE: &'static str = \"Handy Haversacks\";"));
+        assert!(lib.contains("fn part1_example()"));
+
+        let main = std::fs::read_to_string(dir.join("src/main.rs")).unwrap();
+        assert!(main.contains("use day_7_handy_haversacks::HandyHaversacks;"));
+
+        let manifest = std::fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day-7-handy-haversacks\""));
+
+        assert!(dir.join("answers.toml").exists());
+        assert!(dir.join("benches/solution.rs").exists());
+        assert!(dir.join("data").is_dir());
+    }
+
+    #[test]
+    fn registers_the_day_in_order() {
+        // given
+        let root = workspace("register");
+
+        // when
+        scaffold(&root, &Day::new(7, "handy-haversacks").unwrap()).unwrap();
+
+        // then
+        let members = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(members.contains(
+            "    \"day-1-report-repair\",\n    \"day-7-handy-haversacks\",\n    \"day-10-adapter-array\",\n"
+        ));
+
+        let manifest = std::fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(manifest.contains(
+            "day-7-handy-haversacks = { path = \"../day-7-handy-haversacks\" }\nday-10-adapter-array"
+        ));
+
+        let registry = std::fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(registry.contains(
+            "    &day_7_handy_haversacks::HandyHaversacks,\n    &day_10_adapter_array::AdapterArray,\n];"
+        ));
+    }
+
+    #[test]
+    fn appends_days_after_the_last_one() {
+        // given
+        let root = workspace("append");
+
+        // when
+        scaffold(&root, &Day::new(11, "seating-system").unwrap()).unwrap();
+
+        // then
+        let registry = std::fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(registry.contains(
+            "    &day_10_adapter_array::AdapterArray,\n    &day_11_seating_system::SeatingSystem,\n];"
+        ));
+
+        let manifest = std::fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(manifest.contains(
+            "day-11-seating-system = { path = \"../day-11-seating-system\" }\ntoml = \"0.8\""
+        ));
+    }
+
+    #[test]
+    fn refuses_a_day_that_already_exists() {
+        // given
+        let root = workspace("exists");
+
+        // when
+        let error = scaffold(&root, &Day::new(10, "adapters").unwrap()).unwrap_err();
+
+        // then
+        assert_eq!(error, "Day 10 already has a crate");
+        assert!(!root.join("day-10-adapters").exists());
+    }
+
+    #[test]
+    fn new_days_are_left_out_of_verify_until_they_have_input_and_answers() {
+        // given
+        let root = workspace("verify");
+        let dir = scaffold(&root, &Day::new(11, "seating-system").unwrap()).unwrap();
+
+        // when
+        let fresh = crate::verify::not_ready(&dir);
+        write(&dir.join("data/data.txt"), "L.LL.LL.LL\n").unwrap();
+        let fetched = crate::verify::not_ready(&dir);
+        write(&dir.join("answers.toml"), "part1 = 37\n").unwrap();
+        let recorded = crate::verify::not_ready(&dir);
+
+        // then
+        assert!(fresh.unwrap().starts_with("no input at "));
+        assert!(fetched.unwrap().starts_with("no answers recorded in "));
+        assert_eq!(recorded, None);
+    }
+}
@@ -0,0 +1,214 @@
+//! `aoc serve`: every solver behind a local HTTP endpoint, for dashboards that would
+//! rather post an input than shell out to the CLI.
+//!
+//! `POST /2020/day/{n}/part/{p}` with the raw input as body answers with the same JSON object
+//! as `aoc run --format json`, or with `{"error": {"kind", "message", ...}}` and a 4xx/5xx
+//! status. Parse errors carry the `day`, `line`, `column` and `text` they point at.
+
+use crate::days;
+use crate::isolation::{self, Outcome};
+use crate::output;
+use serde_json::json;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+pub fn bind(address: &str) -> Result<Server, String> {
+    Server::http(address).map_err(|e| format!("Cannot listen on {}: {}", address, e))
+}
+
+/// Answers requests until the process is stopped, each one on a thread of its own.
+pub fn serve(server: Server, limit: Duration) {
+    for request in server.incoming_requests() {
+        std::thread::spawn(move || respond(request, limit));
+    }
+}
+
+fn respond(mut request: Request, limit: Duration) {
+    let mut body = vec![];
+    let (status, json) = match request.as_reader().read_to_end(&mut body) {
+        Ok(_) => handle(request.method(), request.url(), &body, limit),
+        Err(e) => error(400, "bad_request", format!("cannot read the body: {}", e)),
+    };
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json + "\n")
+        .with_status_code(status)
+        .with_header(content_type);
+    let _ = request.respond(response);
+}
+
+/// Status and JSON body for a request, with the routing kept apart from any socket.
+pub fn handle(method: &Method, url: &str, body: &[u8], limit: Duration) -> (u16, String) {
+    let (day, part) = match route(url) {
+        Some(route) => route,
+        None => return error(404, "not_found", format!("no such endpoint {}", url)),
+    };
+    if *method != Method::Post {
+        return error(
+            405,
+            "method_not_allowed",
+            format!("{} only accepts POST", url),
+        );
+    }
+    let puzzle = match days::find(day) {
+        Some(puzzle) => puzzle,
+        None => return error(404, "not_found", format!("day {} is not solved yet", day)),
+    };
+    if part != 1 && part != 2 {
+        return error(
+            404,
+            "not_found",
+            format!("day {} has no part {}", day, part),
+        );
+    }
+    let input = match String::from_utf8(body.to_vec()) {
+        Ok(input) => input,
+        Err(_) => return error(400, "bad_request", "the input is not UTF-8".to_owned()),
+    };
+
+    match isolation::solve(puzzle, input.clone(), Some(part), limit) {
+        Outcome::Solved(solved) => (
+            200,
+            output::json_lines(day, &input, &solved, false)
+                .trim_end()
+                .to_owned(),
+        ),
+        Outcome::Failed(e) => (
+            422,
+            json!({
+                "error": {
+                    "kind": "invalid_input",
+                    "day": e.day,
+                    "line": e.line,
+                    "column": e.column,
+                    "text": e.text,
+                    "message": e.message,
+                }
+            })
+            .to_string(),
+        ),
+        Outcome::Panicked(message) => error(500, "panic", message),
+        Outcome::TimedOut(limit) => error(
+            504,
+            "timeout",
+            format!("no answer after {}", crate::timings::duration(limit)),
+        ),
+    }
+}
+
+/// Day and part of `/2020/day/{n}/part/{p}`, ignoring any query string.
+fn route(url: &str) -> Option<(u8, u8)> {
+    let path = url.split('?').next()?;
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        ["2020", "day", day, "part", part] => Some((day.parse().ok()?, part.parse().ok()?)),
+        _ => None,
+    }
+}
+
+fn error(status: u16, kind: &str, message: String) -> (u16, String) {
+    let body = json!({ "error": { "kind": kind, "message": message } });
+    (status, body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: Duration = Duration::from_secs(10);
+
+    fn post(url: &str, body: &str) -> (u16, serde_json::Value) {
+        let (status, json) = handle(&Method::Post, url, body.as_bytes(), LIMIT);
+        (status, serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn answers_a_part() {
+        // when
+        let (status, json) = post(
+            "/2020/day/8/part/2",
+            "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n",
+        );
+
+        // then
+        assert_eq!(status, 200);
+        assert_eq!(json["day"], 8);
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], 8);
+        assert!(json["duration_ns"].is_u64());
+        assert_eq!(json["input_hash"].as_str().unwrap().len(), 64);
+    }
+
+    #[test]
+    fn points_parse_errors_at_the_input() {
+        // when
+        let (status, json) = post("/2020/day/8/part/1", "nop +0\njmp +x\n");
+
+        // then
+        assert_eq!(status, 422);
+        assert_eq!(json["error"]["kind"], "invalid_input");
+        assert_eq!(json["error"]["day"], 8);
+        assert_eq!(json["error"]["line"], 2);
+        assert_eq!(json["error"]["column"], 5);
+        assert_eq!(json["error"]["text"], "jmp +x");
+    }
+
+    #[test]
+    fn rejects_unknown_routes_days_parts_and_methods() {
+        for (method, url, status) in [
+            (Method::Post, "/2020/day/8", 404),
+            (Method::Post, "/2020/day/26/part/1", 404),
+            (Method::Post, "/2020/day/8/part/3", 404),
+            (Method::Get, "/2020/day/8/part/1", 405),
+        ]
+        .iter()
+        {
+            // when
+            let (actual, json) = handle(method, url, b"nop +0\n", LIMIT);
+
+            // then
+            assert_eq!(actual, *status, "{} {}", method, url);
+            assert!(json.contains("\"kind\""), "{}", json);
+        }
+    }
+
+    #[test]
+    fn routes_ignore_slashes_and_queries() {
+        assert_eq!(route("/2020/day/7/part/1"), Some((7, 1)));
+        assert_eq!(
+            route("/2020/day/10/part/2/?source=dashboard"),
+            Some((10, 2))
+        );
+        assert_eq!(route("/2019/day/7/part/1"), None);
+        assert_eq!(route("/2020/day/x/part/1"), None);
+    }
+
+    #[test]
+    fn serves_over_http() {
+        // given
+        let server = bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr());
+        std::thread::spawn(move || serve(server, LIMIT));
+
+        // when
+        let answered = ureq::post(&format!("{}/2020/day/1/part/1", url))
+            .send_string("1721\n979\n366\n299\n675\n1456\n")
+            .unwrap();
+        let rejected = ureq::post(&format!("{}/2020/day/1/part/1", url)).send_string("1721\nabc\n");
+
+        // then
+        assert_eq!(answered.header("Content-Type"), Some("application/json"));
+        let json: serde_json::Value =
+            serde_json::from_str(&answered.into_string().unwrap()).unwrap();
+        assert_eq!(json["answer"], 514579);
+        match rejected {
+            Err(ureq::Error::Status(422, response)) => {
+                let json: serde_json::Value =
+                    serde_json::from_str(&response.into_string().unwrap()).unwrap();
+                assert_eq!(json["error"]["line"], 2);
+            }
+            other => panic!("{:?}", other.map(|response| response.status())),
+        }
+    }
+}
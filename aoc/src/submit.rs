@@ -0,0 +1,420 @@
+//! Answer submission, with a local log of every judged guess so a rejected answer
+//! is never sent twice.
+
+use crate::client::Client;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+        ]
+        .iter()
+        .copied()
+        .find(|verdict| verdict.name() == name)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low."),
+        }
+    }
+}
+
+/// What the server made of a submitted answer.
+#[derive(Debug, PartialEq)]
+pub enum Response {
+    Judged(Verdict),
+    /// Too many answers too quickly, with the remaining wait as the server words it.
+    RateLimited(Option<String>),
+    /// The part is already solved, or locked until the previous part is.
+    WrongLevel,
+}
+
+impl Response {
+    /// Reads the verdict out of the HTML page returned for a submission.
+    pub fn parse(page: &str) -> Result<Response, String> {
+        let text = page
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+
+        if text.contains("That's the right answer") {
+            Ok(Response::Judged(Verdict::Correct))
+        } else if text.contains("That's not the right answer") {
+            Ok(Response::Judged(if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }))
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_owned());
+            Ok(Response::RateLimited(wait))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Response::WrongLevel)
+        } else {
+            Err(format!(
+                "Unrecognised response: {}",
+                strip_tags(text).trim()
+            ))
+        }
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Response::Judged(verdict) => write!(f, "{}", verdict),
+            Response::RateLimited(Some(wait)) => {
+                write!(f, "Answered too recently, {} left to wait.", wait)
+            }
+            Response::RateLimited(None) => write!(f, "Answered too recently, wait a bit."),
+            Response::WrongLevel => write!(f, "Wrong level, is this part already solved?"),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every judged guess, one `day part verdict answer` line each.
+pub struct Log {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl Log {
+    pub fn path() -> PathBuf {
+        crate::days::workspace_root().join("submissions.log")
+    }
+
+    /// Reads a log, starting empty when the file does not exist.
+    pub fn open(path: &Path) -> Result<Log, String> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Cannot read {}: {}", path.display(), e)),
+        };
+
+        let guesses = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                parse_guess(line).ok_or(format!(
+                    "Invalid {} line {}: {}",
+                    path.display(),
+                    index + 1,
+                    line
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Log {
+            path: path.to_owned(),
+            guesses,
+        })
+    }
+
+    pub fn guesses(&self, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| guess.day == day && guess.part == part)
+    }
+
+    /// Explains why `answer` need not be sent, judging by the earlier guesses.
+    pub fn known_verdict(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+
+        self.guesses(day, part).find_map(|guess| {
+            let bound = guess.answer.parse::<i128>().ok();
+            match (guess.verdict, number, bound) {
+                (Verdict::Correct, _, _) if guess.answer == answer => {
+                    Some(format!("{} is already known to be correct", answer))
+                }
+                (Verdict::Correct, _, _) => Some(format!("already solved with {}", guess.answer)),
+                _ if guess.answer == answer => Some(format!(
+                    "{} was already rejected as {}",
+                    answer,
+                    guess.verdict.name()
+                )),
+                (Verdict::TooHigh, Some(number), Some(bound)) if number > bound => Some(format!(
+                    "{} is higher than {}, which was already too high",
+                    answer, bound
+                )),
+                (Verdict::TooLow, Some(number), Some(bound)) if number < bound => Some(format!(
+                    "{} is lower than {}, which was already too low",
+                    answer, bound
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn record(&mut self, guess: Guess) -> Result<(), String> {
+        let line = format!(
+            "{} {} {} {}\n",
+            guess.day,
+            guess.part,
+            guess.verdict.name(),
+            guess.answer
+        );
+
+        use std::io::Write;
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| format!("Cannot write {}: {}", self.path.display(), e))?;
+
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
+
+fn parse_guess(line: &str) -> Option<Guess> {
+    let mut fields = line.splitn(4, ' ');
+    Some(Guess {
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: Verdict::from_name(fields.next()?)?,
+        answer: fields.next()?.to_owned(),
+    })
+}
+
+/// Sends an answer unless the log already tells how it would be judged,
+/// and records the verdict.
+pub fn submit(
+    client: &Client,
+    log: &mut Log,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Response, String> {
+    if let Some(reason) = log.known_verdict(day, part, answer) {
+        return Err(format!(
+            "Not submitting day {} part {}: {}",
+            day, part, reason
+        ));
+    }
+
+    let response = Response::parse(&client.submit(day, part, answer)?)?;
+    if let Response::Judged(verdict) = response {
+        log.record(Guess {
+            day,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+        })?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::fake_server::FakeServer;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    fn client(server: &FakeServer) -> Client {
+        Client::new(&Config {
+            session: Some("abc123".to_owned()),
+            base_url: server.url.to_owned(),
+        })
+        .unwrap()
+    }
+
+    fn empty_log(name: &str) -> Log {
+        let path = std::env::temp_dir().join(format!("aoc-submissions-{}.log", name));
+        let _ = std::fs::remove_file(&path);
+        Log::open(&path).unwrap()
+    }
+
+    #[test]
+    fn parses_every_kind_of_response() {
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer to saving your vacation.",
+                Response::Judged(Verdict::Correct),
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Response::Judged(Verdict::Wrong),
+            ),
+            (
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+                Response::Judged(Verdict::TooHigh),
+            ),
+            (
+                "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+                Response::Judged(Verdict::TooLow),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.",
+                Response::RateLimited(Some("37s".to_owned())),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Response::WrongLevel,
+            ),
+        ];
+
+        for (message, expected) in cases.iter() {
+            assert_eq!(
+                &Response::parse(&page(message)).unwrap(),
+                expected,
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_responses() {
+        // when
+        let error = Response::parse(&page("<em>Something</em> else")).unwrap_err();
+
+        // then
+        assert_eq!(error, "Unrecognised response: Something else");
+    }
+
+    #[test]
+    fn posts_the_answer_and_logs_the_verdict() {
+        // given
+        let server = FakeServer::start(200, &page("That's the right answer!"));
+        let mut log = empty_log("post");
+
+        // when
+        let response = submit(&client(&server), &mut log, 1, 2, "241861950").unwrap();
+
+        // then
+        assert_eq!(response, Response::Judged(Verdict::Correct));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2020/day/1/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=2&answer=241861950");
+
+        let reopened = Log::open(&log.path).unwrap();
+        assert_eq!(
+            reopened.guesses(1, 2).collect::<Vec<_>>(),
+            vec![&Guess {
+                day: 1,
+                part: 2,
+                answer: "241861950".to_owned(),
+                verdict: Verdict::Correct
+            }]
+        );
+    }
+
+    #[test]
+    fn never_submits_a_known_wrong_answer_twice() {
+        // given
+        let server = FakeServer::start(200, &page("That's not the right answer."));
+        let mut log = empty_log("twice");
+        submit(&client(&server), &mut log, 9, 1, "127").unwrap();
+
+        // when
+        let error = submit(&client(&server), &mut log, 9, 1, "127").unwrap_err();
+
+        // then
+        assert!(error.contains("127 was already rejected as wrong"));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn skips_answers_beyond_known_bounds() {
+        // given
+        let server = FakeServer::start(
+            200,
+            &page("That's not the right answer; your answer is too high."),
+        );
+        let mut log = empty_log("bounds");
+        submit(&client(&server), &mut log, 10, 2, "19208").unwrap();
+
+        // when
+        let error = submit(&client(&server), &mut log, 10, 2, "20000").unwrap_err();
+
+        // then
+        assert!(error.contains("already too high"));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn does_not_log_rate_limited_answers() {
+        // given
+        let server = FakeServer::start(
+            200,
+            &page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 2s left to wait."),
+        );
+        let mut log = empty_log("rate-limit");
+
+        // when
+        let response = submit(&client(&server), &mut log, 5, 1, "820").unwrap();
+
+        // then
+        assert_eq!(response, Response::RateLimited(Some("1m 2s".to_owned())));
+        assert_eq!(log.guesses(5, 1).count(), 0);
+    }
+}
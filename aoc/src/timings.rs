@@ -0,0 +1,144 @@
+//! Summary of where the time goes, most expensive day first.
+
+use aoc_common::{Phase, Timing};
+use std::time::Duration;
+
+pub struct Row {
+    pub day: u8,
+    pub title: &'static str,
+    pub timings: Vec<Timing>,
+}
+
+impl Row {
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(|t| t.duration).sum()
+    }
+
+    fn duration(&self, phase: Phase) -> String {
+        self.timings
+            .iter()
+            .find(|t| t.phase == phase)
+            .map_or("-".to_owned(), |t| duration(t.duration))
+    }
+
+    fn peak_bytes(&self) -> Option<usize> {
+        self.timings.iter().filter_map(|t| t.peak_bytes).max()
+    }
+}
+
+pub fn table(mut rows: Vec<Row>, allocations: bool) -> String {
+    rows.sort_by_key(|row| std::cmp::Reverse(row.total()));
+
+    let mut table = format!(
+        "{:>3}  {:<20}  {:>9}  {:>9}  {:>9}  {:>9}",
+        "day", "title", "parse", "part 1", "part 2", "total"
+    );
+    if allocations {
+        table.push_str(&format!("  {:>10}", "peak heap"));
+    }
+    table.push('\n');
+
+    for row in &rows {
+        table.push_str(&format!(
+            "{:>3}  {:<20}  {:>9}  {:>9}  {:>9}  {:>9}",
+            row.day,
+            row.title,
+            row.duration(Phase::Parse),
+            row.duration(Phase::Part(1)),
+            row.duration(Phase::Part(2)),
+            duration(row.total())
+        ));
+        if allocations {
+            table.push_str(&format!(
+                "  {:>10}",
+                row.peak_bytes().map_or("-".to_owned(), bytes)
+            ));
+        }
+        table.push('\n');
+    }
+
+    table
+}
+
+pub fn duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+pub fn bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u8, micros: &[u64]) -> Row {
+        let phases = [Phase::Parse, Phase::Part(1), Phase::Part(2)];
+        Row {
+            day,
+            title: "Report Repair",
+            timings: phases
+                .iter()
+                .zip(micros)
+                .map(|(&phase, &micros)| Timing {
+                    phase,
+                    duration: Duration::from_micros(micros),
+                    peak_bytes: Some(micros as usize * 1024),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn sorts_days_by_total_time() {
+        // given
+        let rows = vec![
+            row(1, &[1, 1, 1]),
+            row(7, &[10, 20, 30]),
+            row(3, &[5, 5, 5]),
+        ];
+
+        // when
+        let table = table(rows, false);
+
+        // then
+        let days: Vec<_> = table
+            .lines()
+            .skip(1)
+            .map(|line| line.split_whitespace().next().unwrap())
+            .collect();
+        assert_eq!(days, vec!["7", "3", "1"]);
+        assert!(!table.contains("peak heap"));
+    }
+
+    #[test]
+    fn shows_missing_parts_and_peak_heap() {
+        // when
+        let table = table(vec![row(1, &[3, 2000])], true);
+
+        // then
+        let line = table.lines().nth(1).unwrap();
+        assert!(line.contains("3.0µs"));
+        assert!(line.contains("2.00ms"));
+        assert!(line.contains(" - "));
+        assert!(line.ends_with("2.0 MiB"));
+    }
+
+    #[test]
+    fn formats_durations_and_sizes() {
+        assert_eq!(duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(duration(Duration::from_millis(1500)), "1.50s");
+        assert_eq!(bytes(100), "100 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+    }
+}
@@ -0,0 +1,204 @@
+//! Golden answer checks: every day is solved on its `data/data.txt` and compared with
+//! the answers recorded next to it in `answers.toml`.
+
+use crate::days;
+use aoc_common::Puzzle;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Outcome of verifying a single part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    /// Known answer, `None` when `answers.toml` does not record one yet.
+    pub expected: Option<String>,
+    /// Computed answer, or the error that stopped the day from being solved.
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Ok(actual)) => expected == actual,
+            (None, Ok(_)) => true,
+            (_, Err(_)) => false,
+        }
+    }
+}
+
+pub fn answers_file(puzzle: &dyn Puzzle) -> PathBuf {
+    days::crate_dir(puzzle).join("answers.toml")
+}
+
+/// Reads `part1` and `part2` from an answers file. A missing file means no known answers.
+pub fn load_answers(path: &Path) -> Result<BTreeMap<u8, String>, String> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    let table = content
+        .parse::<toml::Table>()
+        .map_err(|e| format!("Invalid {}: {}", path.display(), e))?;
+
+    (1..=2)
+        .filter_map(|part| table.get(&format!("part{}", part)).map(|v| (part, v)))
+        .map(|(part, value)| match value {
+            toml::Value::String(answer) => Ok((part, answer.to_owned())),
+            toml::Value::Integer(answer) => Ok((part, answer.to_string())),
+            other => Err(format!(
+                "Invalid {}: part{} should be a string or an integer, not {}",
+                path.display(),
+                part,
+                other.type_str()
+            )),
+        })
+        .collect()
+}
+
+/// Solves the day on `input` and compares both parts with the answers in `answers`.
+pub fn verify(puzzle: &dyn Puzzle, input: &Path, answers: &Path) -> Vec<Check> {
+    let expected = load_answers(answers);
+    let solved = std::fs::read_to_string(input)
+        .map_err(|e| format!("Cannot read {}: {}", input.display(), e))
+        .and_then(|input| puzzle.solve(&input, None).map_err(|e| e.to_string()));
+
+    (1..=2)
+        .map(|part| {
+            let actual = solved
+                .as_ref()
+                .map_err(|e| e.to_owned())
+                .and_then(|answers| {
+                    answers
+                        .iter()
+                        .find(|(p, _)| *p == part)
+                        .map(|(_, answer)| answer.to_string())
+                        .ok_or_else(|| format!("part {} was not solved", part))
+                });
+
+            match &expected {
+                Ok(expected) => Check {
+                    day: puzzle.day(),
+                    part,
+                    expected: expected.get(&part).cloned(),
+                    actual,
+                },
+                Err(message) => Check {
+                    day: puzzle.day(),
+                    part,
+                    expected: None,
+                    actual: Err(message.to_owned()),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Why the day's crate in `dir` cannot be verified yet, if it cannot: a day just created
+/// by `aoc new` has no `data/data.txt` until fetched, and no answers until recorded.
+pub fn not_ready(dir: &Path) -> Option<String> {
+    let input = dir.join("data").join("data.txt");
+    if !input.exists() {
+        return Some(format!("no input at {}", input.display()));
+    }
+
+    let answers = dir.join("answers.toml");
+    match load_answers(&answers) {
+        Ok(known) if known.is_empty() => {
+            Some(format!("no answers recorded in {}", answers.display()))
+        }
+        _ => None,
+    }
+}
+
+/// Verifies every registered day against its own data and answers files, and lists the
+/// days left out with why, as told by [`not_ready`].
+pub fn verify_all() -> (Vec<Check>, Vec<(u8, String)>) {
+    let mut checks = vec![];
+    let mut skipped = vec![];
+
+    for puzzle in days::DAYS {
+        match not_ready(&days::crate_dir(*puzzle)) {
+            Some(reason) => skipped.push((puzzle.day(), reason)),
+            None => checks.extend(verify(
+                *puzzle,
+                &days::default_input(*puzzle),
+                &answers_file(*puzzle),
+            )),
+        }
+    }
+
+    (checks, skipped)
+}
+
+/// Table of the failed checks, one row per part.
+pub fn mismatch_table(checks: &[Check]) -> String {
+    let mut table = format!(
+        "{:>3}  {:>4}  {:<20}  {}\n",
+        "day", "part", "expected", "actual"
+    );
+
+    for check in checks.iter().filter(|c| !c.passed()) {
+        table.push_str(&format!(
+            "{:>3}  {:>4}  {:<20}  {}\n",
+            check.day,
+            check.part,
+            check.expected.as_deref().unwrap_or("-"),
+            match &check.actual {
+                Ok(answer) => answer.to_owned(),
+                Err(message) => format!("error: {}", message.replace('\n', "\n      ")),
+            }
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(expected: Option<&str>, actual: Result<&str, &str>) -> Check {
+        Check {
+            day: 1,
+            part: 1,
+            expected: expected.map(|e| e.to_owned()),
+            actual: actual.map(|a| a.to_owned()).map_err(|e| e.to_owned()),
+        }
+    }
+
+    #[test]
+    fn passes_when_answers_match() {
+        assert!(check(Some("42"), Ok("42")).passed());
+    }
+
+    #[test]
+    fn fails_when_answers_differ() {
+        assert!(!check(Some("42"), Ok("43")).passed());
+    }
+
+    #[test]
+    fn passes_when_no_answer_is_known() {
+        assert!(check(None, Ok("43")).passed());
+    }
+
+    #[test]
+    fn fails_when_the_day_errors() {
+        assert!(!check(Some("42"), Err("day 1: no two expenses sum to 2020")).passed());
+    }
+
+    #[test]
+    fn lists_only_mismatches() {
+        // given
+        let checks = vec![check(Some("42"), Ok("42")), check(Some("42"), Ok("43"))];
+
+        // when
+        let table = mismatch_table(&checks);
+
+        // then
+        assert_eq!(table.lines().count(), 2);
+        assert!(table.lines().last().unwrap().ends_with("43"));
+    }
+}
@@ -0,0 +1,289 @@
+//! `aoc watch`: rebuild and rerun a day whenever its sources or input change.
+//!
+//! The runner cannot reload its own code, so every change goes through cargo: the day's
+//! tests (the puzzle examples) first, then a freshly built `aoc run <day> --format json`.
+
+use crate::days;
+use crate::timings;
+use aoc_common::Puzzle;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+const POLL: Duration = Duration::from_millis(300);
+
+/// Modification time and length of every file below some directories.
+#[derive(Debug, PartialEq)]
+pub struct Snapshot(BTreeMap<PathBuf, (SystemTime, u64)>);
+
+impl Snapshot {
+    pub fn take(dirs: &[PathBuf]) -> Snapshot {
+        let mut files = BTreeMap::new();
+        for dir in dirs {
+            collect(dir, &mut files);
+        }
+        Snapshot(files)
+    }
+
+    /// Files added, removed or modified since `earlier`.
+    pub fn changes<'a>(&'a self, earlier: &'a Snapshot) -> Vec<&'a Path> {
+        let modified = self
+            .0
+            .iter()
+            .filter(|(path, stamp)| earlier.0.get(*path) != Some(stamp))
+            .map(|(path, _)| path.as_path());
+        let removed = earlier
+            .0
+            .keys()
+            .filter(|path| !self.0.contains_key(*path))
+            .map(PathBuf::as_path);
+
+        modified.chain(removed).collect()
+    }
+}
+
+fn collect(dir: &Path, files: &mut BTreeMap<PathBuf, (SystemTime, u64)>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => collect(&path, files),
+            Ok(metadata) => {
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                files.insert(path, (modified, metadata.len()));
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+/// One answer of a run, as read back from `aoc run --format json`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    pub part: u8,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+pub fn parse_run(json_lines: &str) -> Result<Vec<Part>, String> {
+    json_lines
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let record: serde_json::Value = serde_json::from_str(line)
+                .map_err(|e| format!("Unexpected runner output {:?}: {}", line, e))?;
+            let answer = match &record["answer"] {
+                serde_json::Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+
+            Ok(Part {
+                part: record["part"].as_u64().unwrap_or(0) as u8,
+                answer,
+                duration: Duration::from_nanos(record["duration_ns"].as_u64().unwrap_or(0)),
+            })
+        })
+        .collect()
+}
+
+/// Each answer with its timing, and how both moved since the previous run.
+pub fn report(previous: &[Part], current: &[Part]) -> String {
+    current
+        .iter()
+        .map(|part| {
+            let before = previous.iter().find(|p| p.part == part.part);
+            let answer = match before {
+                Some(before) if before.answer != part.answer => {
+                    format!("{} (was {})", part.answer, before.answer)
+                }
+                _ => part.answer.clone(),
+            };
+            let change = match before {
+                Some(before) if !before.duration.is_zero() => format!(
+                    " ({:+.0}%, was {})",
+                    (part.duration.as_secs_f64() / before.duration.as_secs_f64() - 1.0) * 100.0,
+                    timings::duration(before.duration)
+                ),
+                _ => String::new(),
+            };
+
+            format!(
+                "[part {}] {}  {}{}\n",
+                part.part,
+                answer,
+                timings::duration(part.duration),
+                change
+            )
+        })
+        .collect()
+}
+
+/// Reruns the day now and after every change to its `src/` or `data/`, until interrupted.
+pub fn watch(puzzle: &dyn Puzzle) -> Result<(), String> {
+    let dir = days::crate_dir(puzzle);
+    let krate = dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or(format!("Unexpected crate directory {}", dir.display()))?
+        .to_owned();
+    let watched = [dir.join("src"), dir.join("data")];
+
+    let mut previous = vec![];
+    let mut snapshot = Snapshot::take(&watched);
+    loop {
+        if let Some(current) = rerun(puzzle.day(), &krate) {
+            print!("{}", report(&previous, &current));
+            previous = current;
+        }
+        println!("Watching {}/{{src,data}} for changes...", dir.display());
+
+        loop {
+            sleep(POLL);
+            let next = Snapshot::take(&watched);
+            if let Some(path) = next.changes(&snapshot).first() {
+                println!("\n{} changed", path.display());
+                // editors often write in several steps, give them a moment to finish
+                sleep(POLL);
+                snapshot = Snapshot::take(&watched);
+                break;
+            }
+        }
+    }
+}
+
+fn rerun(day: u8, krate: &str) -> Option<Vec<Part>> {
+    let tests = cargo(&["test", "-q", "-p", krate])?;
+    if tests.status.success() {
+        println!("Examples pass");
+    } else {
+        print!("{}", String::from_utf8_lossy(&tests.stdout));
+        eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+        println!("Examples fail");
+    }
+
+    let run = cargo(&[
+        "run",
+        "-q",
+        "--release",
+        "-p",
+        "aoc",
+        "--",
+        "run",
+        &day.to_string(),
+        "--format",
+        "json",
+        "--no-cache",
+    ])?;
+    if !run.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&run.stderr));
+        return None;
+    }
+
+    match parse_run(&String::from_utf8_lossy(&run.stdout)) {
+        Ok(parts) => Some(parts),
+        Err(message) => {
+            eprintln!("{}", message);
+            None
+        }
+    }
+}
+
+fn cargo(args: &[&str]) -> Option<std::process::Output> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+
+    Command::new(&cargo)
+        .args(args)
+        .current_dir(days::workspace_root())
+        .output()
+        .map_err(|e| eprintln!("Cannot run {}: {}", cargo, e))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(part: u8, answer: &str, micros: u64) -> Part {
+        Part {
+            part,
+            answer: answer.to_owned(),
+            duration: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn notices_added_modified_and_removed_files() {
+        // given
+        let dir = std::env::temp_dir().join("aoc-watch-snapshot");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "fn a() {}").unwrap();
+        std::fs::write(dir.join("src/main.rs"), "fn main() {}").unwrap();
+        let before = Snapshot::take(&[dir.join("src"), dir.join("data")]);
+
+        // when
+        std::fs::write(dir.join("src/lib.rs"), "fn ab() {}").unwrap();
+        std::fs::remove_file(dir.join("src/main.rs")).unwrap();
+        std::fs::create_dir_all(dir.join("data")).unwrap();
+        std::fs::write(dir.join("data/data.txt"), "1\n").unwrap();
+        let after = Snapshot::take(&[dir.join("src"), dir.join("data")]);
+
+        // then
+        let mut changes = after.changes(&before);
+        changes.sort();
+        assert_eq!(
+            changes,
+            vec![
+                dir.join("data/data.txt").as_path(),
+                dir.join("src/lib.rs").as_path(),
+                dir.join("src/main.rs").as_path(),
+            ]
+        );
+        assert!(after.changes(&after).is_empty());
+    }
+
+    #[test]
+    fn reads_back_json_runs() {
+        // when
+        let parts = parse_run(
+            "{\"day\":8,\"part\":1,\"answer\":1818,\"duration_ns\":17000,\"input_hash\":\"ab\"}\n\
+             {\"day\":8,\"part\":2,\"answer\":\"abc\",\"duration_ns\":2000000,\"input_hash\":\"ab\"}\n",
+        )
+        .unwrap();
+
+        // then
+        assert_eq!(parts, vec![part(1, "1818", 17), part(2, "abc", 2000)]);
+        assert!(parse_run("error: could not compile").is_err());
+    }
+
+    #[test]
+    fn reports_the_first_run_without_a_diff() {
+        // when
+        let report = report(&[], &[part(1, "1818", 17), part(2, "631", 2000)]);
+
+        // then
+        assert_eq!(report, "[part 1] 1818  17.0µs\n[part 2] 631  2.00ms\n");
+    }
+
+    #[test]
+    fn reports_changed_answers_and_timings() {
+        // given
+        let previous = [part(1, "1818", 20), part(2, "630", 2000)];
+
+        // when
+        let report = report(&previous, &[part(1, "1818", 15), part(2, "631", 3000)]);
+
+        // then
+        assert_eq!(
+            report,
+            "[part 1] 1818  15.0µs (-25%, was 20.0µs)\n\
+             [part 2] 631 (was 630)  3.00ms (+50%, was 2.00ms)\n"
+        );
+    }
+}
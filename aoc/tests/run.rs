@@ -0,0 +1,138 @@
+#[test]
+fn prints_typed_answers_as_json_lines() {
+    // when
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "8", "--format", "json"])
+        .output()
+        .unwrap();
+
+    // then
+    assert!(output.status.success());
+
+    let lines: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["day"], 8);
+    assert_eq!(lines[0]["part"], 1);
+    assert_eq!(lines[0]["answer"], 1818);
+    assert_eq!(lines[1]["answer"], 631);
+    assert!(lines[1]["duration_ns"].is_u64());
+    assert_eq!(lines[0]["input_hash"], lines[1]["input_hash"]);
+}
+
+#[test]
+fn generates_inputs_every_day_can_solve() {
+    for day in 1..=10 {
+        // given
+        let path = std::env::temp_dir().join(format!("aoc-generated-day-{}.txt", day));
+        let generate = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["generate", &day.to_string(), "--seed", "7", "--size", "50"])
+            .arg("--output")
+            .arg(&path)
+            .status()
+            .unwrap();
+        assert!(generate.success());
+
+        // when
+        let run = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["run", &day.to_string(), "--input"])
+            .arg(&path)
+            .output()
+            .unwrap();
+
+        // then
+        assert!(
+            run.status.success(),
+            "day {}: {}",
+            day,
+            String::from_utf8_lossy(&run.stderr)
+        );
+    }
+}
+
+#[test]
+fn reuses_cached_answers_unless_told_not_to() {
+    let run = |args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["run", "8"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // given
+    run(&[]);
+
+    // when
+    let cached = run(&[]);
+    let uncached = run(&["--no-cache"]);
+
+    // then
+    assert!(
+        cached.starts_with("Day 8: Handheld Halting (cached)\n"),
+        "{}",
+        cached
+    );
+    assert!(cached.contains("[part 1] 1818"), "{}", cached);
+    assert!(
+        uncached.starts_with("Day 8: Handheld Halting\n"),
+        "{}",
+        uncached
+    );
+}
+
+#[test]
+fn reports_days_out_of_time_and_carries_on() {
+    // when
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--all", "--no-cache", "--time-limit", "0.000001"])
+        .output()
+        .unwrap();
+
+    // then
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let solved = stdout
+        .lines()
+        .filter(|line| line.starts_with("Day "))
+        .count();
+    let timed_out = stderr
+        .lines()
+        .filter(|line| line.contains(": TIMEOUT  "))
+        .count();
+    assert!(!output.status.success());
+    assert!(timed_out > 0, "{}", stderr);
+    assert_eq!(solved + timed_out, 10, "{}{}", stdout, stderr);
+}
+
+#[test]
+fn reports_cyclic_rules_instead_of_overflowing_the_stack() {
+    // given
+    let path = std::env::temp_dir().join("aoc-cyclic-day-7.txt");
+    std::fs::write(
+        &path,
+        "shiny gold bags contain 1 dark olive bag.\ndark olive bags contain 1 shiny gold bag.\n",
+    )
+    .unwrap();
+
+    // when
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "7", "--no-cache", "--input"])
+        .arg(&path)
+        .output()
+        .unwrap();
+
+    // then
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stderr.contains("shiny gold bags end up containing themselves"),
+        "{}",
+        stderr
+    );
+}
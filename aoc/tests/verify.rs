@@ -0,0 +1,65 @@
+use aoc::{days, verify};
+
+#[test]
+fn every_day_matches_its_known_answers() {
+    // when
+    let (checks, _) = verify::verify_all();
+
+    // then
+    assert!(
+        checks.iter().all(|c| c.passed()),
+        "\n{}",
+        verify::mismatch_table(&checks)
+    );
+}
+
+#[test]
+fn every_day_records_its_answers() {
+    // when
+    let (checks, _) = verify::verify_all();
+
+    // then
+    assert!(checks.iter().all(|c| c.expected.is_some()));
+}
+
+#[test]
+fn reports_a_changed_answer() {
+    // given
+    let puzzle = days::find(1).unwrap();
+    let answers = std::env::temp_dir().join("aoc-verify-changed-answer.toml");
+    std::fs::write(&answers, "part1 = 270144\npart2 = \"1\"\n").unwrap();
+
+    // when
+    let checks = verify::verify(puzzle, &days::default_input(puzzle), &answers);
+
+    // then
+    let failed = checks.iter().filter(|c| !c.passed()).collect::<Vec<_>>();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].part, 2);
+    assert_eq!(failed[0].expected.as_deref(), Some("1"));
+    assert_eq!(failed[0].actual.as_deref(), Ok("261342720"));
+}
+
+#[test]
+fn exits_successfully_when_answers_match() {
+    // when
+    let status = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify"])
+        .status()
+        .unwrap();
+
+    // then
+    assert!(status.success());
+}
+
+#[test]
+fn exits_with_failure_for_unknown_day() {
+    // when
+    let status = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify", "25"])
+        .status()
+        .unwrap();
+
+    // then
+    assert!(!status.success());
+}
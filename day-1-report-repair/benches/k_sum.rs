@@ -0,0 +1,58 @@
+use aoc_common::{bench, Solution};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_1_report_repair::{find, k_sum, matches, Entries, ReportRepair};
+
+/// `k_sum` against trying every combination with `find`, and `k_sum::count` against
+/// counting `matches`, on generated expense reports small enough to try every triple.
+fn benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-1-k-sum");
+    group.sample_size(10);
+
+    for size in [50, 100, 200].iter().copied() {
+        let entries = ReportRepair::parse(&bench::generated::<ReportRepair>(size)).unwrap();
+
+        for k in [2, 3].iter().copied() {
+            let name = format!("k={}", k);
+            group.bench_with_input(
+                BenchmarkId::new(format!("find/{}", name), size),
+                &entries,
+                |b, entries| {
+                    b.iter(|| {
+                        find(black_box(entries), k, Entries::Distinct, |v| {
+                            v.iter().sum::<i32>() == 2020
+                        })
+                    })
+                },
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("k_sum/{}", name), size),
+                &entries,
+                |b, entries| b.iter(|| k_sum(black_box(entries), k, 2020, Entries::Distinct)),
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("matches/{}", name), size),
+                &entries,
+                |b, entries| {
+                    b.iter(|| {
+                        matches(black_box(entries), k, Entries::Distinct, |v| {
+                            v.iter().sum::<i32>() == 2020
+                        })
+                        .count()
+                    })
+                },
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("count/{}", name), size),
+                &entries,
+                |b, entries| {
+                    b.iter(|| k_sum::count(black_box(entries), k, 2020, Entries::Distinct))
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -0,0 +1,227 @@
+//! Every combination of expenses reaching a target, for checking a report by hand rather
+//! than only multiplying the first pair and triple.
+
+use crate::{entry_lines, k_sum, matches, minimal_subsets, Entries, Match, ReportRepair};
+use aoc_common::{Result, Solution};
+
+/// Matches listed for each size, or smallest subsets, before the rest are only counted.
+const SHOWN: usize = 5;
+
+/// For each size, how many combinations of that many entries sum to `target`, and with
+/// `list` the lines and values of the first few.
+///
+/// Counting stays quick for larger sizes, though counts saturate at `u64::MAX`, but listing
+/// tries every combination of entries until it has found as many as it shows, which takes
+/// long beyond triples.
+pub fn summary(input: &str, target: i64, sizes: &[usize], list: bool) -> Result<String> {
+    let input = aoc_common::normalize(input);
+    let entries = ReportRepair::parse(&input)?;
+    let lines = entry_lines(&input);
+
+    let mut summary = String::new();
+    for &size in sizes {
+        let count = k_sum::count(&entries, size, target, Entries::Distinct);
+        summary.push_str(&format!(
+            "{} entries summing to {}: {} match{}\n",
+            size,
+            target,
+            count,
+            if count == 1 { "" } else { "es" }
+        ));
+
+        if !list || count == 0 {
+            continue;
+        }
+
+        let shown = count.min(SHOWN as u64) as usize;
+        let sums_to_target = |v: &Vec<i32>| v.iter().map(|&e| i64::from(e)).sum::<i64>() == target;
+        for found in matches(&entries, size, Entries::Distinct, sums_to_target).take(shown) {
+            summary.push_str(&describe(&found, &lines));
+        }
+        if count > SHOWN as u64 {
+            summary.push_str(&format!("  ...and {} more\n", count - SHOWN as u64));
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Lines and values of the first of the smallest subsets of entries summing to `target`,
+/// of at most `max_size` entries when given. With `all`, how many such subsets there are
+/// and the first few of them instead.
+pub fn smallest_subsets(
+    input: &str,
+    target: i64,
+    max_size: Option<usize>,
+    all: bool,
+) -> Result<String> {
+    let input = aoc_common::normalize(input);
+    let entries = ReportRepair::parse(&input)?;
+    let lines = entry_lines(&input);
+
+    let subsets = minimal_subsets(&entries, target, max_size);
+    let total = subsets.total();
+    let mut subsets = subsets.peekable();
+    let size = match subsets.peek() {
+        Some(first) => first.indices.len(),
+        None => return Ok(String::new()),
+    };
+    if !all {
+        return Ok(describe(&subsets.next().unwrap(), &lines));
+    }
+
+    // the total saturates rather than overflow
+    let (total, more) = match total {
+        u64::MAX => (format!("over {}", u64::MAX - 1), "many".to_owned()),
+        total => (
+            total.to_string(),
+            total.saturating_sub(SHOWN as u64).to_string(),
+        ),
+    };
+    let mut summary = format!(
+        "{} smallest subset{} of {} entries summing to {}\n",
+        total,
+        if total == "1" { "" } else { "s" },
+        size,
+        target
+    );
+    for found in subsets.take(SHOWN) {
+        summary.push_str(&describe(&found, &lines));
+    }
+    if more != "0" {
+        summary.push_str(&format!("  ...and {} more\n", more));
+    }
+
+    Ok(summary)
+}
+
+fn describe(found: &Match, lines: &[usize]) -> String {
+    if found.indices.is_empty() {
+        return "  no entries\n".to_owned();
+    }
+
+    let lines = found
+        .lines(lines)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let values = found
+        .values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    format!("  lines {}: {}\n", lines.join(", "), values.join(" + "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarises_the_example() {
+        // given
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+
+        // when
+        let summary = summary(input, 2020, &[2, 3], true).unwrap();
+
+        // then
+        assert_eq!(
+            summary,
+            "2 entries summing to 2020: 1 match\n\
+             \x20 lines 1, 4: 1721 + 299\n\
+             3 entries summing to 2020: 1 match\n\
+             \x20 lines 2, 3, 5: 979 + 366 + 675\n"
+        );
+    }
+
+    #[test]
+    fn lists_the_first_matches_and_counts_the_rest() {
+        // given
+        let input = (0..=10).map(|i| format!("{}\n", i)).collect::<String>();
+
+        // when
+        let summary = summary(&input, 10, &[2], true).unwrap();
+
+        // then
+        assert_eq!(
+            summary,
+            "2 entries summing to 10: 5 matches\n\
+             \x20 lines 1, 11: 0 + 10\n\
+             \x20 lines 2, 10: 1 + 9\n\
+             \x20 lines 3, 9: 2 + 8\n\
+             \x20 lines 4, 8: 3 + 7\n\
+             \x20 lines 5, 7: 4 + 6\n"
+        );
+        assert!(super::summary(&input, 1, &[3], true)
+            .unwrap()
+            .starts_with("3 entries summing to 1: 0 matches\n"));
+        assert!(super::summary(&input, 15, &[3], true)
+            .unwrap()
+            .ends_with("  ...and 8 more\n"));
+    }
+
+    #[test]
+    fn points_at_invalid_entries() {
+        assert_eq!(
+            summary("1\n\nx\n", 2020, &[2], false).unwrap_err().line,
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn only_counts_unless_asked_to_list() {
+        // given
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+
+        // when
+        let summary = summary(input, 2020, &[2, 6], false).unwrap();
+
+        // then
+        assert_eq!(
+            summary,
+            "2 entries summing to 2020: 1 match\n\
+             6 entries summing to 2020: 0 matches\n"
+        );
+    }
+
+    #[test]
+    fn describes_the_smallest_subsets() {
+        // given
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+
+        // then
+        assert_eq!(
+            smallest_subsets(input, 2020, None, false).unwrap(),
+            "  lines 1, 4: 1721 + 299\n"
+        );
+        assert_eq!(
+            smallest_subsets(input, 2020, Some(3), true).unwrap(),
+            "1 smallest subset of 2 entries summing to 2020\n\
+             \x20 lines 1, 4: 1721 + 299\n"
+        );
+        assert_eq!(smallest_subsets(input, 2020, Some(1), true).unwrap(), "");
+        assert_eq!(
+            smallest_subsets(input, 0, None, false).unwrap(),
+            "  no entries\n"
+        );
+    }
+
+    #[test]
+    fn counts_the_smallest_subsets_beyond_those_it_lists() {
+        // given
+        let input = (1..=8).map(|_| "1\n").collect::<String>();
+        let many = (1..=200).map(|_| "1\n").collect::<String>();
+
+        // when
+        let summary = smallest_subsets(&input, 2, None, true).unwrap();
+
+        // then
+        assert!(summary
+            .starts_with("28 smallest subsets of 2 entries summing to 2\n  lines 1, 2: 1 + 1\n"));
+        assert!(summary.ends_with("  lines 1, 6: 1 + 1\n  ...and 23 more\n"));
+        assert!(smallest_subsets(&many, 100, None, true)
+            .unwrap()
+            .ends_with("  ...and many more\n"));
+    }
+}
@@ -0,0 +1,98 @@
+//! Expense reports with exactly one pair and one triple summing to 2020.
+//!
+//! The planted entries are all below 1010 except the pair's larger half, and every other
+//! entry lies in 1011..=2019, so any two of those already sum past 2020. Filler values
+//! that would complete a pair or triple with the planted entries are skipped.
+
+use aoc_common::Rng;
+use itertools::Itertools;
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let small = planted(rng);
+    let pair = 2020 - small[0];
+
+    let forbidden = small
+        .iter()
+        .map(|x| 2020 - x)
+        .chain(
+            small
+                .iter()
+                .combinations_with_replacement(2)
+                .map(|xy| 2020 - xy[0] - xy[1]),
+        )
+        .collect::<Vec<i64>>();
+
+    let mut expenses = small.to_vec();
+    expenses.push(pair);
+    while expenses.len() < size {
+        let filler = rng.range(1011..=2019);
+        if !forbidden.contains(&filler) {
+            expenses.push(filler);
+        }
+    }
+
+    rng.shuffle(&mut expenses);
+    expenses.iter().map(|e| format!("{}\n", e)).collect()
+}
+
+/// Distinct `[a, b, c, d]` below 1010 where `b + c + d` is the only triple of them
+/// (or of them and `2020 - a`) to reach 2020.
+fn planted(rng: &mut Rng) -> [i64; 4] {
+    loop {
+        let a = rng.range(1..=1009);
+        let b = rng.range(2..=1009);
+        let c = rng.range((1011 - b).max(1)..=(2019 - b).min(1009));
+        let small = [a, b, c, 2020 - b - c];
+
+        let distinct = small.iter().unique().count() == 4;
+        let triples = small
+            .iter()
+            .combinations_with_replacement(3)
+            .filter(|xyz| xyz.iter().copied().sum::<i64>() == 2020)
+            .count();
+        let with_pair = small
+            .iter()
+            .combinations_with_replacement(2)
+            .any(|xy| xy[0] + xy[1] == a);
+
+        if distinct && triples == 1 && !with_pair {
+            return small;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReportRepair;
+    use aoc_common::Solution;
+
+    fn sums_to_2020(expenses: &[i32], amount: usize) -> usize {
+        (0..expenses.len())
+            .combinations_with_replacement(amount)
+            .filter(|indices| indices.iter().map(|&i| expenses[i]).sum::<i32>() == 2020)
+            .count()
+    }
+
+    #[test]
+    fn plants_exactly_one_pair_and_one_triple() {
+        for seed in 0..20 {
+            // given
+            let input = input(&mut Rng::new(seed), 60);
+
+            // when
+            let expenses = ReportRepair::parse(&input).unwrap();
+
+            // then
+            assert_eq!(expenses.len(), 60);
+            assert_eq!(sums_to_2020(&expenses, 2), 1, "seed {}", seed);
+            assert_eq!(sums_to_2020(&expenses, 3), 1, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        assert_eq!(input(&mut Rng::new(5), 100), input(&mut Rng::new(5), 100));
+        assert_ne!(input(&mut Rng::new(5), 100), input(&mut Rng::new(6), 100));
+    }
+}
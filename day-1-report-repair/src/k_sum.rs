@@ -0,0 +1,376 @@
+//! Entries summing to a target without trying every combination like [`find`](crate::find):
+//! a hash map for pairs, two pointers over the sorted entries for triples, and meet in the
+//! middle beyond that. [`count`] counts every match without listing any.
+
+use crate::{Entries, Match};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Some `k` entries summing to `target`, or `None` when there are none.
+pub fn k_sum(list: &[i32], k: usize, target: i64, entries: Entries) -> Option<Match> {
+    let indices = match k {
+        0 => (target == 0).then(Vec::new),
+        1 => list
+            .iter()
+            .position(|&entry| i64::from(entry) == target)
+            .map(|index| vec![index]),
+        2 => pair(list, target, entries),
+        3 => triple(&sorted(list, entries), target, entries),
+        _ => meet_in_the_middle(&sorted(list, entries), k, target, entries),
+    }?;
+
+    Some(Match::of(list, indices))
+}
+
+/// How many combinations of `k` entries sum to `target`, as many as [`matches`](crate::matches)
+/// would list, without going through them one by one.
+///
+/// Goes through the entries once, keeping how many combinations of each smaller size reach
+/// each sum so far; combinations of size `k` only need to be counted when they hit `target`.
+/// Counts saturate at `u64::MAX`, which 20 of 200 entries of 0 already exceed.
+pub fn count(list: &[i32], k: usize, target: i64, entries: Entries) -> u64 {
+    if k == 0 {
+        return (target == 0) as u64;
+    }
+
+    let mut sums = vec![HashMap::<i64, u64>::new(); k];
+    sums[0].insert(0, 1);
+    let mut count: u64 = 0;
+
+    for &entry in list {
+        let entry = i64::from(entry);
+        // a target out of reach of any sum makes the subtraction overflow
+        let completing = |sums: &[HashMap<i64, u64>]| match target.checked_sub(entry) {
+            Some(missing) => sums[k - 1].get(&missing).copied().unwrap_or(0),
+            None => 0,
+        };
+        let extend = |sums: &mut Vec<HashMap<i64, u64>>, size: usize| {
+            let extended = sums[size - 1]
+                .iter()
+                .map(|(&sum, &ways)| (sum + entry, ways))
+                .collect::<Vec<_>>();
+            for (sum, ways) in extended {
+                let total = sums[size].entry(sum).or_insert(0);
+                *total = total.saturating_add(ways);
+            }
+        };
+
+        match entries {
+            // sizes upwards, so this entry can extend combinations it is already part of
+            Entries::Reused => {
+                (1..k).for_each(|size| extend(&mut sums, size));
+                count = count.saturating_add(completing(&sums));
+            }
+            // sizes downwards, so this entry only extends combinations of earlier entries
+            Entries::Distinct => {
+                count = count.saturating_add(completing(&sums));
+                (1..k).rev().for_each(|size| extend(&mut sums, size));
+            }
+        }
+    }
+
+    count
+}
+
+/// Values and indices of the entries by value. Reused entries only need the first of
+/// equal values, since it can be picked as often as it takes.
+fn sorted(list: &[i32], entries: Entries) -> Vec<(i64, usize)> {
+    let mut sorted = list
+        .iter()
+        .enumerate()
+        .map(|(index, &entry)| (i64::from(entry), index))
+        .collect::<Vec<_>>();
+    sorted.sort_unstable();
+    if entries == Entries::Reused {
+        sorted.dedup_by_key(|(value, _)| *value);
+    }
+    sorted
+}
+
+/// One pass, looking each entry's complement up among the entries seen so far.
+fn pair(list: &[i32], target: i64, entries: Entries) -> Option<Vec<usize>> {
+    let mut seen = HashMap::new();
+
+    for (index, &entry) in list.iter().enumerate() {
+        let entry = i64::from(entry);
+        if entries == Entries::Reused {
+            seen.entry(entry).or_insert(index);
+        }
+
+        // no entry completes a target out of reach of any sum
+        let other = target
+            .checked_sub(entry)
+            .and_then(|missing| seen.get(&missing));
+        if let Some(&other) = other {
+            return Some(vec![other, index]);
+        }
+        seen.entry(entry).or_insert(index);
+    }
+
+    None
+}
+
+/// For each smallest entry, closes in on the other two from both ends of the sorted entries.
+fn triple(sorted: &[(i64, usize)], target: i64, entries: Entries) -> Option<Vec<usize>> {
+    let reused = entries == Entries::Reused;
+
+    for (first, &(a, index)) in sorted.iter().enumerate() {
+        let mut low = if reused { first } else { first + 1 };
+        let mut high = sorted.len() - 1;
+
+        while low < high || (reused && low == high) {
+            let ((b, second), (c, third)) = (sorted[low], sorted[high]);
+            match (a + b + c).cmp(&target) {
+                Ordering::Equal => return Some(vec![index, second, third]),
+                Ordering::Less => low += 1,
+                Ordering::Greater if high == 0 => break,
+                Ordering::Greater => high -= 1,
+            }
+        }
+    }
+
+    None
+}
+
+/// Sums of every `k / 2` entries go into a map, then every remaining `k - k / 2` entries
+/// look up the sum they are missing.
+///
+/// Distinct entries need both halves apart: taking the positions of a match in sorted
+/// order, its first half lies wholly before its second. So each sum keeps the half that
+/// ends earliest, and a lookup only accepts it when it ends before the other half starts.
+fn meet_in_the_middle(
+    sorted: &[(i64, usize)],
+    k: usize,
+    target: i64,
+    entries: Entries,
+) -> Option<Vec<usize>> {
+    let halves = |size| -> Box<dyn Iterator<Item = Vec<usize>>> {
+        match entries {
+            Entries::Reused => Box::new((0..sorted.len()).combinations_with_replacement(size)),
+            Entries::Distinct => Box::new((0..sorted.len()).combinations(size)),
+        }
+    };
+    let sum = |half: &[usize]| half.iter().map(|&p| sorted[p].0).sum::<i64>();
+
+    let mut firsts = HashMap::<i64, Vec<usize>>::new();
+    for half in halves(k / 2) {
+        let earliest = firsts.entry(sum(&half)).or_insert_with(|| half.clone());
+        if half.last() < earliest.last() {
+            *earliest = half;
+        }
+    }
+
+    halves(k - k / 2).find_map(|second| {
+        let first = firsts.get(&target.checked_sub(sum(&second))?)?;
+        let apart = entries == Entries::Reused || first.last() < second.first();
+        apart.then(|| first.iter().chain(&second).map(|&p| sorted[p].1).collect())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find, matches};
+    use aoc_common::differential::{self, shrink_vec};
+    use aoc_common::Rng;
+
+    const EXAMPLE: [i32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    fn values(found: Option<Match>) -> Option<Vec<i32>> {
+        found.map(|found| found.values)
+    }
+
+    #[test]
+    fn finds_the_example_pair_and_triple() {
+        for entries in [Entries::Reused, Entries::Distinct].iter().copied() {
+            assert_eq!(
+                k_sum(&EXAMPLE, 2, 2020, entries),
+                Some(Match {
+                    indices: vec![0, 3],
+                    values: vec![1721, 299]
+                })
+            );
+            assert_eq!(
+                values(k_sum(&EXAMPLE, 3, 2020, entries)),
+                Some(vec![979, 366, 675])
+            );
+        }
+    }
+
+    #[test]
+    fn picks_an_entry_more_than_once_only_when_reused() {
+        assert_eq!(
+            values(k_sum(&[1010, 7], 2, 2020, Entries::Reused)),
+            Some(vec![1010, 1010])
+        );
+        assert_eq!(k_sum(&[1010, 7], 2, 2020, Entries::Distinct), None);
+        assert_eq!(
+            values(k_sum(&[600, 820, 5], 3, 2020, Entries::Reused)),
+            Some(vec![600, 600, 820])
+        );
+        assert_eq!(k_sum(&[600, 820, 5], 3, 2020, Entries::Distinct), None);
+        assert_eq!(
+            values(k_sum(&[505], 4, 2020, Entries::Reused)),
+            Some(vec![505; 4])
+        );
+        assert_eq!(k_sum(&[505], 4, 2020, Entries::Distinct), None);
+    }
+
+    #[test]
+    fn counts_equal_values_as_separate_entries() {
+        assert_eq!(
+            k_sum(&[7, 1010, 3, 1010], 2, 2020, Entries::Distinct),
+            Some(Match {
+                indices: vec![1, 3],
+                values: vec![1010, 1010]
+            })
+        );
+        assert_eq!(
+            k_sum(&[600, 1, 820, 600], 3, 2020, Entries::Distinct).map(|m| m.indices),
+            Some(vec![0, 2, 3])
+        );
+        assert_eq!(
+            k_sum(&[505, 9, 505, 505, 505], 4, 2020, Entries::Distinct).map(|m| m.indices),
+            Some(vec![0, 2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn handles_small_and_large_k() {
+        let distinct = Entries::Distinct;
+
+        assert_eq!(values(k_sum(&EXAMPLE, 0, 0, distinct)), Some(vec![]));
+        assert_eq!(k_sum(&EXAMPLE, 0, 2020, distinct), None);
+        assert_eq!(
+            k_sum(&EXAMPLE, 1, 979, distinct).map(|m| m.indices),
+            Some(vec![1])
+        );
+        assert_eq!(k_sum(&EXAMPLE, 1, 2020, distinct), None);
+        assert_eq!(
+            values(k_sum(&EXAMPLE, 5, 1721 + 979 + 366 + 299 + 675, distinct)),
+            Some(vec![1721, 979, 366, 299, 675])
+        );
+        assert_eq!(k_sum(&EXAMPLE, 7, 0, distinct), None);
+        assert_eq!(k_sum(&[], 3, 0, distinct), None);
+        assert_eq!(
+            values(k_sum(&[-5, 3, 10], 4, -20, Entries::Reused)),
+            Some(vec![-5; 4])
+        );
+    }
+
+    #[test]
+    fn finds_sums_whenever_trying_every_combination_does() {
+        // given
+        let generate = |rng: &mut Rng| {
+            let k = rng.range(1..=5) as usize;
+            let entries = if rng.chance(1, 2) {
+                Entries::Reused
+            } else {
+                Entries::Distinct
+            };
+            let mut list = (0..rng.range(0..=8))
+                .map(|_| rng.range(-100..=1500) as i32)
+                .collect::<Vec<_>>();
+            if rng.chance(1, 2) && !list.is_empty() {
+                // plant a combination reaching 2020 with its last pick
+                let picks = (1..k).map(|_| *rng.pick(&list)).sum::<i32>();
+                list.push(2020 - picks);
+                rng.shuffle(&mut list);
+            }
+            (k, entries, list)
+        };
+        let shrink = |(k, entries, list): &(usize, Entries, Vec<i32>)| {
+            shrink_vec(list)
+                .into_iter()
+                .map(|list| (*k, *entries, list))
+                .collect()
+        };
+
+        // then
+        differential::compare(
+            1000,
+            generate,
+            shrink,
+            |(k, entries, list)| {
+                find(list, *k, *entries, |v| v.iter().sum::<i32>() == 2020).is_some()
+            },
+            |(k, entries, list)| match k_sum(list, *k, 2020, *entries) {
+                Some(found) => {
+                    assert_eq!(found.indices.len(), *k);
+                    assert_eq!(found.values.iter().sum::<i32>(), 2020);
+                    assert!(found
+                        .indices
+                        .iter()
+                        .zip(&found.values)
+                        .all(|(&i, &v)| list[i] == v));
+                    if *entries == Entries::Distinct {
+                        assert!(found.indices.windows(2).all(|w| w[0] < w[1]));
+                    }
+                    true
+                }
+                None => false,
+            },
+        );
+    }
+
+    #[test]
+    fn counts_as_many_as_there_are_matches() {
+        // given
+        let generate = |rng: &mut Rng| {
+            let k = rng.range(0..=4) as usize;
+            let entries = if rng.chance(1, 2) {
+                Entries::Reused
+            } else {
+                Entries::Distinct
+            };
+            let list = (0..rng.range(0..=9))
+                .map(|_| rng.range(-3..=6) as i32)
+                .collect::<Vec<_>>();
+            (k, entries, list, rng.range(-4..=12))
+        };
+        let shrink = |(k, entries, list, target): &(usize, Entries, Vec<i32>, i64)| {
+            shrink_vec(list)
+                .into_iter()
+                .map(|list| (*k, *entries, list, *target))
+                .collect()
+        };
+
+        // then
+        differential::compare(
+            1000,
+            generate,
+            shrink,
+            |(k, entries, list, target)| {
+                let target = *target;
+                matches(list, *k, *entries, move |v| {
+                    v.iter().map(|&e| i64::from(e)).sum::<i64>() == target
+                })
+                .count() as u64
+            },
+            |(k, entries, list, target)| count(list, *k, *target, *entries),
+        );
+    }
+
+    #[test]
+    fn counts_up_to_the_largest_count_and_targets_out_of_reach() {
+        // given
+        let zeros = [0; 200];
+
+        // then
+        assert_eq!(count(&zeros, 20, 0, Entries::Distinct), u64::MAX);
+        assert_eq!(count(&zeros, 2, 0, Entries::Distinct), 199 * 100);
+        assert_eq!(count(&EXAMPLE, 2, i64::MIN, Entries::Distinct), 0);
+        assert_eq!(count(&[-1, -2], 2, i64::MAX, Entries::Reused), 0);
+    }
+
+    #[test]
+    fn finds_nothing_for_targets_out_of_reach() {
+        for k in 0..=5 {
+            for entries in [Entries::Reused, Entries::Distinct].iter().copied() {
+                assert_eq!(k_sum(&EXAMPLE, k, i64::MIN, entries), None);
+                assert_eq!(k_sum(&[-1, -2, -3], k, i64::MAX, entries), None);
+            }
+        }
+    }
+}
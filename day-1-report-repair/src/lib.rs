@@ -0,0 +1,269 @@
+//! [Day 1: Report Repair](https://adventofcode.com/2020/day/1): find the expenses that sum
+//! to 2020 and multiply them.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use std::str::FromStr;
+
+pub mod audit;
+mod generate;
+pub mod k_sum;
+pub mod subset_sum;
+
+pub use k_sum::k_sum;
+pub use subset_sum::{minimal_subsets, subset_sum};
+
+/// Whether a combination may pick the same entry more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entries {
+    /// An entry may be picked again, so a single 1010 makes a pair summing to 2020.
+    Reused,
+    /// Each entry is picked at most once, though equal values on different lines are
+    /// different entries.
+    Distinct,
+}
+
+/// Entries picked for a combination.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Positions of the entries in the list, in ascending order.
+    pub indices: Vec<usize>,
+    /// The entries themselves, in the same order as `indices`.
+    pub values: Vec<i32>,
+}
+
+impl Match {
+    fn of(list: &[i32], mut indices: Vec<usize>) -> Match {
+        indices.sort_unstable();
+        let values = indices.iter().map(|&i| list[i]).collect();
+        Match { indices, values }
+    }
+
+    /// Input lines of the entries, given the line of every entry from [`entry_lines`].
+    pub fn lines(&self, entry_lines: &[usize]) -> Vec<usize> {
+        self.indices.iter().map(|&i| entry_lines[i]).collect()
+    }
+}
+
+/// What to make of the entries of a match, the puzzle asking for their product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduce {
+    /// The entries multiplied together.
+    Product,
+    /// The entries added up.
+    Sum,
+    /// The largest entry.
+    Max,
+}
+
+impl Reduce {
+    /// The entries reduced to one number, or `None` when it overflows or there are no
+    /// entries to take the largest of.
+    pub fn apply(self, values: &[i32]) -> Option<i64> {
+        let mut values = values.iter().map(|&value| i64::from(value));
+        match self {
+            Reduce::Product => values.try_fold(1i64, |product, value| product.checked_mul(value)),
+            Reduce::Sum => values.try_fold(0i64, |sum, value| sum.checked_add(value)),
+            Reduce::Max => values.max(),
+        }
+    }
+}
+
+impl FromStr for Reduce {
+    type Err = String;
+
+    fn from_str(name: &str) -> std::result::Result<Self, Self::Err> {
+        match name {
+            "product" => Ok(Reduce::Product),
+            "sum" => Ok(Reduce::Sum),
+            "max" => Ok(Reduce::Max),
+            _ => Err(format!("expected product, sum or max, not {:?}", name)),
+        }
+    }
+}
+
+/// The 1-based line each entry of the parsed input comes from, blank lines being skipped.
+pub fn entry_lines(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// Every combination of `amount` entries whose values satisfy `predicate`, lazily and in
+/// ascending order of their indices. [`k_sum::count`] counts sums without listing them.
+pub fn matches<'a>(
+    list: &'a [i32],
+    amount: usize,
+    entries: Entries,
+    predicate: impl Fn(&Vec<i32>) -> bool + 'a,
+) -> impl Iterator<Item = Match> + 'a {
+    use itertools::Itertools;
+
+    let combinations: Box<dyn Iterator<Item = Vec<usize>>> = match entries {
+        Entries::Reused => Box::new((0..list.len()).combinations_with_replacement(amount)),
+        Entries::Distinct => Box::new((0..list.len()).combinations(amount)),
+    };
+
+    combinations
+        .map(move |indices| Match::of(list, indices))
+        .filter(move |found| predicate(&found.values))
+}
+
+/// First combination of `amount` entries whose values satisfy `predicate`, trying them
+/// all. [`k_sum()`] is much faster when the predicate is a sum.
+pub fn find(
+    list: &[i32],
+    amount: usize,
+    entries: Entries,
+    predicate: impl Fn(&Vec<i32>) -> bool,
+) -> Option<Match> {
+    matches(list, amount, entries, predicate).next()
+}
+
+/// The puzzle as a [`Solution`]: the product of the pair, then of the triple, summing to 2020.
+pub struct ReportRepair;
+
+impl Solution for ReportRepair {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Report Repair";
+    const VERSION: &'static str = "3";
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_lines(input, |line| aoc_common::number(Self::DAY, line))
+    }
+
+    fn part1(expense_list: &Self::Input) -> Result<Self::Answer1> {
+        let pair = k_sum(expense_list, 2, 2020, Entries::Distinct)
+            .ok_or_else(|| Error::new(Self::DAY, "no two expenses sum to 2020"))?;
+
+        Ok(pair.values.iter().product())
+    }
+
+    fn part2(expense_list: &Self::Input) -> Result<Self::Answer2> {
+        let triple = k_sum(expense_list, 3, 2020, Entries::Distinct)
+            .ok_or_else(|| Error::new(Self::DAY, "no three expenses sum to 2020"))?;
+
+        Ok(triple.values.iter().product())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gets_the_pair_expense() {
+        // given
+        let list = vec![1721, 979, 366, 299, 675, 1456];
+
+        // when
+        let expenses = find(&list, 2, Entries::Reused, |v| v.iter().sum::<i32>() == 2020).unwrap();
+        let product: i32 = expenses.values.iter().product();
+
+        // then
+        assert_eq!(product, 514579);
+    }
+
+    #[test]
+    fn gets_the_triple_expense() {
+        // given
+        let list = vec![1721, 979, 366, 299, 675, 1456];
+
+        // when
+        let expenses = find(&list, 3, Entries::Reused, |v| v.iter().sum::<i32>() == 2020).unwrap();
+        let product: i32 = expenses.values.iter().product();
+
+        // then
+        assert_eq!(product, 241861950);
+    }
+
+    #[test]
+    fn reports_the_line_of_an_invalid_expense() {
+        // given
+        let input = "1721\n979\n3 66\n";
+
+        // when
+        let error = ReportRepair::parse(input).unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.text, "3 66");
+    }
+
+    #[test]
+    fn picks_each_entry_at_most_once_when_distinct() {
+        // given
+        let sums_to_2020 = |v: &Vec<i32>| v.iter().sum::<i32>() == 2020;
+
+        // when
+        let reused = find(&[1010, 7], 2, Entries::Reused, sums_to_2020);
+        let distinct = find(&[1010, 7], 2, Entries::Distinct, sums_to_2020);
+        let duplicated = find(&[7, 1010, 3, 1010], 2, Entries::Distinct, sums_to_2020);
+
+        // then
+        assert_eq!(reused.map(|found| found.indices), Some(vec![0, 0]));
+        assert_eq!(distinct, None);
+        assert_eq!(
+            duplicated,
+            Some(Match {
+                indices: vec![1, 3],
+                values: vec![1010, 1010]
+            })
+        );
+    }
+
+    #[test]
+    fn does_not_pair_an_expense_with_itself() {
+        // given
+        let input = "1010\n979\n366\n1041\n675\n";
+
+        // when
+        let expenses = ReportRepair::parse(input).unwrap();
+
+        // then
+        assert_eq!(ReportRepair::part1(&expenses).unwrap(), 979 * 1041);
+    }
+
+    #[test]
+    fn lists_every_match_with_its_lines() {
+        // given
+        let input = "1000\n\n1020\n500\n1520\n20\n";
+        let list = ReportRepair::parse(input).unwrap();
+
+        // when
+        let found = matches(&list, 2, Entries::Distinct, |v| {
+            v.iter().sum::<i32>() == 2020
+        })
+        .map(|found| found.lines(&entry_lines(input)))
+        .collect::<Vec<_>>();
+
+        // then
+        assert_eq!(found, vec![vec![1, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn reduces_matches_to_one_number() {
+        // given
+        let values = [979, 366, 675];
+
+        // then
+        assert_eq!(Reduce::Product.apply(&values), Some(241861950));
+        assert_eq!(Reduce::Sum.apply(&values), Some(2020));
+        assert_eq!(Reduce::Max.apply(&values), Some(979));
+        assert_eq!(Reduce::Max.apply(&[]), None);
+        assert_eq!(Reduce::Product.apply(&[i32::MAX; 3]), None);
+        assert_eq!("sum".parse(), Ok(Reduce::Sum));
+        assert!("mean".parse::<Reduce>().is_err());
+    }
+}
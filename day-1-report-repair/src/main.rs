@@ -1,66 +1,87 @@
-// https://adventofcode.com/2020/day/1
+use aoc_common::Solution;
+use clap::Parser;
+use day_1_report_repair::{audit, k_sum, subset_sum, Entries, Match, Reduce, ReportRepair};
+use std::path::PathBuf;
 
-pub fn find(list: &[i32], amount: usize, predicate: fn(&Vec<i32>) -> bool) -> Option<Vec<i32>> {
-    use itertools::Itertools;
-
-    list.iter()
-        .cloned()
-        .combinations_with_replacement(amount)
-        .find(predicate)
+/// Finds expenses summing to a target, by default the pair and the triple summing to 2020,
+/// then counts every combination that does. With --any-size, finds the fewest expenses
+/// summing to it instead
+#[derive(Parser)]
+#[command(name = "day-1-report-repair")]
+struct Args {
+    /// Expense report with one entry per line [default: data/data.txt]
+    input: Option<PathBuf>,
+    /// Sum the entries have to reach
+    #[arg(long, default_value_t = 2020, allow_negative_numbers = true)]
+    target: i64,
+    /// How many entries to pick, repeat it for several sizes
+    #[arg(long = "size", value_name = "SIZE", default_values_t = [2, 3], conflicts_with = "any_size")]
+    sizes: Vec<usize>,
+    /// What the answer makes of the entries: product, sum or max
+    #[arg(long, default_value = "product")]
+    reduce: Reduce,
+    /// Also lists the lines of the first few combinations of each size, trying every
+    /// combination of entries to find them
+    #[arg(long, conflicts_with = "any_size")]
+    list: bool,
+    /// Picks as few entries as possible, however many that is
+    #[arg(long)]
+    any_size: bool,
+    /// Picks no more entries than this with --any-size
+    #[arg(long, value_name = "SIZE", requires = "any_size")]
+    max_size: Option<usize>,
+    /// Counts every smallest subset with --any-size, listing the first few
+    #[arg(long, requires = "any_size")]
+    all_minimal: bool,
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = std::env::args().collect();
+fn main() {
+    let args = Args::parse();
     let path = args
-        .get(1)
-        .expect("The first argument should be the data file path");
-    let input_data = std::fs::read_to_string(path)?;
-
-    let expense_list = input_data
-        .split('\n')
-        .into_iter()
-        .map(|f| f.parse().unwrap())
-        .collect::<Vec<i32>>();
-
-    let pair = find(&expense_list, 2, |v| v.iter().sum::<i32>() == 2020).unwrap();
-    let triple = find(&expense_list, 3, |v| v.iter().sum::<i32>() == 2020).unwrap();
-
-    println!("[part 1] {:?} => {:?}", pair, pair.iter().product::<i32>());
-    println!(
-        "[part 2] {:?} => {:?}",
-        triple,
-        triple.iter().product::<i32>()
-    );
-    Ok(())
-}
+        .input
+        .unwrap_or_else(|| PathBuf::from(aoc_common::data_file!()));
+    let input = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("Cannot read {}: {}", path.display(), e);
+        std::process::exit(1);
+    });
+    let list = ReportRepair::parse(&aoc_common::normalize(&input)).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    if args.any_size {
+        let answer = match subset_sum(&list, args.target, args.max_size) {
+            Some(found) => reduce(args.reduce, &found),
+            None => match args.max_size {
+                Some(max) => format!("no {} entries or fewer sum to {}", max, args.target),
+                None => format!("no entries sum to {}", args.target),
+            },
+        };
+        println!("[any size] {}", answer);
 
-    #[test]
-    fn gets_the_pair_expense() {
-        // given
-        let list = vec![1721, 979, 366, 299, 675, 1456];
-
-        // when
-        let expenses = find(&list, 2, |v| v.iter().sum::<i32>() == 2020).unwrap();
-        let product: i32 = expenses.iter().product();
-
-        // then
-        assert_eq!(product, 514579);
+        let subsets = audit::smallest_subsets(&input, args.target, args.max_size, args.all_minimal);
+        print!("{}", subsets.unwrap());
+        return;
     }
 
-    #[test]
-    fn gets_the_triple_expense() {
-        // given
-        let list = vec![1721, 979, 366, 299, 675, 1456];
+    for &size in &args.sizes {
+        let answer = match k_sum(&list, size, args.target, Entries::Distinct) {
+            Some(found) => reduce(args.reduce, &found),
+            None => format!("no {} entries sum to {}", size, args.target),
+        };
+        println!("[{} entries] {}", size, answer);
+    }
 
-        // when
-        let expenses = find(&list, 3, |v| v.iter().sum::<i32>() == 2020).unwrap();
-        let product: i32 = expenses.iter().product();
+    // the input has already parsed, so the summary cannot fail
+    print!(
+        "{}",
+        audit::summary(&input, args.target, &args.sizes, args.list).unwrap()
+    );
+}
 
-        // then
-        assert_eq!(product, 241861950);
+fn reduce(reduce: Reduce, found: &Match) -> String {
+    match reduce.apply(&found.values) {
+        Some(answer) => answer.to_string(),
+        None => format!("cannot reduce {:?}", found.values),
     }
 }
@@ -0,0 +1,305 @@
+//! Subsets of any size summing to a target, by dynamic programming over the sums the
+//! entries can reach, rather than going through every combination of every size.
+//!
+//! Each entry is picked at most once, as with [`Entries::Distinct`](crate::Entries), and
+//! the subsets found are always the smallest ones.
+
+use crate::Match;
+use std::collections::hash_map::{Entry, HashMap};
+
+/// One of the smallest subsets of entries summing to `target`, of at most `max_size`
+/// entries when given, and the first of them in the order of [`minimal_subsets`]. The
+/// empty subset is the answer for a `target` of 0.
+pub fn subset_sum(list: &[i32], target: i64, max_size: Option<usize>) -> Option<Match> {
+    minimal_subsets(list, target, max_size).next()
+}
+
+/// Every smallest subset of entries summing to `target`, of at most `max_size` entries when
+/// given, lazily and in ascending order of their indices.
+///
+/// There can be exponentially many of them: 200 entries of 1 have about 10⁵⁸ subsets
+/// summing to 100. Each one takes time linear in the length of the list to find, so take
+/// only as many as needed, and ask [`MinimalSubsets::total`] how many there are.
+pub fn minimal_subsets(list: &[i32], target: i64, max_size: Option<usize>) -> MinimalSubsets<'_> {
+    let fewest = fewest(list, target, max_size);
+    let (branches, total) = match fewest[0].get(&target) {
+        Some(smallest) => (vec![(0, target, smallest.size, vec![])], smallest.ways),
+        None => (vec![], 0),
+    };
+
+    MinimalSubsets {
+        list,
+        fewest,
+        branches,
+        total,
+    }
+}
+
+/// Iterator over the subsets of [`minimal_subsets`].
+pub struct MinimalSubsets<'a> {
+    list: &'a [i32],
+    fewest: Vec<HashMap<i64, Smallest>>,
+    /// Index of the next entry to pick or leave out, the sum and size still missing and
+    /// the indices picked so far, for every branch of the search left to follow.
+    branches: Vec<(usize, i64, usize, Vec<usize>)>,
+    total: u64,
+}
+
+impl MinimalSubsets<'_> {
+    /// How many subsets there are in all, whether yielded yet or not, saturating at
+    /// `u64::MAX`.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+impl Iterator for MinimalSubsets<'_> {
+    type Item = Match;
+
+    /// Follows the branch that picks an entry before the one that leaves it out, so
+    /// subsets come in ascending order. Every branch ends in a subset, since `fewest`
+    /// only holds sums that reach `target`.
+    fn next(&mut self) -> Option<Match> {
+        while let Some((index, sum, size, mut picked)) = self.branches.pop() {
+            if index == self.list.len() {
+                return Some(Match::of(self.list, picked));
+            }
+
+            let after = &self.fewest[index + 1];
+            if after.get(&sum).map(|smallest| smallest.size) == Some(size) {
+                self.branches.push((index + 1, sum, size, picked.clone()));
+            }
+
+            let rest = sum - i64::from(self.list[index]);
+            if size > 0 && after.get(&rest).map(|smallest| smallest.size) == Some(size - 1) {
+                picked.push(index);
+                self.branches.push((index + 1, rest, size - 1, picked));
+            }
+        }
+
+        None
+    }
+}
+
+/// Fewest entries reaching a sum, and in how many ways.
+#[derive(Debug, Clone, Copy)]
+struct Smallest {
+    size: usize,
+    ways: u64,
+}
+
+/// For each suffix of the list, the fewest of its entries reaching each sum. Sums the rest
+/// of the list cannot bring to `target` anymore are left out, which keeps a list of
+/// non-negative entries to sums between 0 and `target`.
+fn fewest(list: &[i32], target: i64, max_size: Option<usize>) -> Vec<HashMap<i64, Smallest>> {
+    let max_size = max_size.unwrap_or(list.len());
+
+    // lowest and highest sums the entries before each index can add
+    let mut reach = vec![(0, 0); list.len() + 1];
+    for (index, &entry) in list.iter().enumerate() {
+        let (low, high) = reach[index];
+        let entry = i64::from(entry);
+        reach[index + 1] = (low + entry.min(0), high + entry.max(0));
+    }
+    let useful = |index: usize, sum: i64| {
+        let (low, high) = reach[index];
+        (low..=high).contains(&(target - sum))
+    };
+
+    let mut fewest = vec![HashMap::new(); list.len() + 1];
+    if useful(list.len(), 0) {
+        fewest[list.len()].insert(0, Smallest { size: 0, ways: 1 });
+    }
+
+    for (index, &entry) in list.iter().enumerate().rev() {
+        let (before, after) = fewest.split_at_mut(index + 1);
+        let (before, after) = (&mut before[index], &after[0]);
+
+        for (&sum, &smallest) in after {
+            let mut keep = |sum: i64, found: Smallest| {
+                if !useful(index, sum) {
+                    return;
+                }
+                match before.entry(sum) {
+                    Entry::Vacant(vacant) => {
+                        vacant.insert(found);
+                    }
+                    Entry::Occupied(mut occupied) => {
+                        let fewest = occupied.get_mut();
+                        if found.size < fewest.size {
+                            *fewest = found;
+                        } else if found.size == fewest.size {
+                            fewest.ways = fewest.ways.saturating_add(found.ways);
+                        }
+                    }
+                }
+            };
+
+            keep(sum, smallest);
+            if smallest.size < max_size {
+                let picked = Smallest {
+                    size: smallest.size + 1,
+                    ways: smallest.ways,
+                };
+                keep(sum + i64::from(entry), picked);
+            }
+        }
+    }
+
+    fewest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::differential::{self, shrink_vec};
+    use aoc_common::Rng;
+
+    const EXAMPLE: [i32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    /// Indices of every subset summing to `target`, trying them all, keeping the smallest.
+    fn brute_force(list: &[i32], target: i64, max_size: Option<usize>) -> Vec<Vec<usize>> {
+        let subsets = (0u32..1 << list.len())
+            .map(|mask| {
+                (0..list.len())
+                    .filter(|&i| mask & (1 << i) != 0)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|indices| max_size.is_none_or(|max| indices.len() <= max))
+            .filter(|indices| indices.iter().map(|&i| i64::from(list[i])).sum::<i64>() == target)
+            .collect::<Vec<_>>();
+
+        let smallest = subsets.iter().map(Vec::len).min();
+        let mut smallest = subsets
+            .into_iter()
+            .filter(|indices| Some(indices.len()) == smallest)
+            .collect::<Vec<_>>();
+        smallest.sort();
+        smallest
+    }
+
+    #[test]
+    fn finds_the_example_pair_before_the_triple() {
+        // when
+        let found = subset_sum(&EXAMPLE, 2020, None);
+
+        // then
+        assert_eq!(
+            found,
+            Some(Match {
+                indices: vec![0, 3],
+                values: vec![1721, 299]
+            })
+        );
+    }
+
+    #[test]
+    fn caps_the_subset_size() {
+        // given
+        let list = [505, 1020, 505, 3, 505, 1000, 505];
+
+        // then
+        assert_eq!(subset_sum(&list, 2020, Some(1)), None);
+        assert_eq!(
+            subset_sum(&list, 2020, Some(2)).map(|found| found.values),
+            Some(vec![1020, 1000])
+        );
+        assert_eq!(subset_sum(&list, 1515, Some(2)), None);
+        assert_eq!(
+            subset_sum(&list, 1515, Some(3)).map(|found| found.values),
+            Some(vec![505; 3])
+        );
+        assert_eq!(minimal_subsets(&list, 1515, Some(3)).count(), 4);
+    }
+
+    #[test]
+    fn lists_every_smallest_subset() {
+        // given
+        let list = [1010, 5, 1010, 2015, 1010];
+
+        // when
+        let subsets = minimal_subsets(&list, 2020, None).collect::<Vec<_>>();
+
+        // then
+        assert_eq!(
+            subsets
+                .iter()
+                .map(|found| found.indices.clone())
+                .collect::<Vec<_>>(),
+            vec![vec![0, 2], vec![0, 4], vec![1, 3], vec![2, 4]]
+        );
+        assert_eq!(
+            minimal_subsets(&list, 0, None).collect::<Vec<_>>(),
+            vec![Match::of(&list, vec![])]
+        );
+        assert_eq!(minimal_subsets(&list, 1, None).next(), None);
+        assert_eq!(minimal_subsets(&list, 2020, Some(1)).next(), None);
+    }
+
+    #[test]
+    fn finds_the_same_subsets_as_trying_every_one() {
+        // given
+        let generate = |rng: &mut Rng| {
+            let list = (0..rng.range(0..=10))
+                .map(|_| rng.range(-5..=12) as i32)
+                .collect::<Vec<_>>();
+            let max_size = if rng.chance(1, 2) {
+                Some(rng.range(0..=4) as usize)
+            } else {
+                None
+            };
+            (list, rng.range(-8..=30), max_size)
+        };
+        let shrink = |(list, target, max_size): &(Vec<i32>, i64, Option<usize>)| {
+            shrink_vec(list)
+                .into_iter()
+                .map(|list| (list, *target, *max_size))
+                .collect()
+        };
+
+        // then
+        differential::compare(
+            1000,
+            generate,
+            shrink,
+            |(list, target, max_size)| brute_force(list, *target, *max_size),
+            |(list, target, max_size)| {
+                let found = minimal_subsets(list, *target, *max_size);
+                let total = found.total();
+                let subsets = found.collect::<Vec<_>>();
+                assert_eq!(total, subsets.len() as u64);
+                let one = subset_sum(list, *target, *max_size);
+                assert_eq!(one.is_some(), !subsets.is_empty());
+                assert!(one.is_none_or(|one| subsets.contains(&one)));
+
+                subsets
+                    .into_iter()
+                    .map(|found| found.indices)
+                    .collect::<Vec<_>>()
+            },
+        );
+    }
+
+    #[test]
+    fn counts_and_lists_lazily_when_there_are_too_many_subsets() {
+        // given
+        let list = [1; 200];
+
+        // when
+        let subsets = minimal_subsets(&list, 100, None);
+
+        // then
+        assert_eq!(subsets.total(), u64::MAX);
+        assert_eq!(
+            subsets
+                .take(2)
+                .map(|found| found.indices)
+                .collect::<Vec<_>>(),
+            vec![
+                (0..100).collect::<Vec<_>>(),
+                (0..99).chain(Some(100)).collect()
+            ]
+        );
+        assert_eq!(minimal_subsets(&list, 2, None).total(), 199 * 100);
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::Solution;
+use day_1_report_repair::{find, Entries, Match, ReportRepair};
+
+const EXAMPLE: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+#[test]
+fn finds_entries_through_the_public_api() {
+    // given
+    let expenses = ReportRepair::parse(EXAMPLE).unwrap();
+
+    // when
+    let pair = find(&expenses, 2, Entries::Distinct, |v| {
+        v.iter().sum::<i32>() == 2020
+    });
+
+    // then
+    assert_eq!(
+        pair,
+        Some(Match {
+            indices: vec![0, 3],
+            values: vec![1721, 299]
+        })
+    );
+}
+
+#[test]
+fn solves_the_example() {
+    // given
+    let expenses = ReportRepair::parse(EXAMPLE).unwrap();
+
+    // then
+    assert_eq!(ReportRepair::part1(&expenses).unwrap(), 514579);
+    assert_eq!(ReportRepair::part2(&expenses).unwrap(), 241861950);
+}
@@ -0,0 +1,125 @@
+use std::process::Command;
+
+const EXAMPLE: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+fn day_1(name: &str, input: &str, args: &[&str]) -> String {
+    let path = std::env::temp_dir().join(format!("aoc-day-1-cli-{}.txt", name));
+    std::fs::write(&path, input).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_day-1-report-repair"))
+        .arg(&path)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn answers_the_puzzle_by_default() {
+    // when
+    let output = day_1("default", EXAMPLE, &[]);
+
+    // then
+    assert_eq!(
+        output,
+        "[2 entries] 514579\n\
+         [3 entries] 241861950\n\
+         2 entries summing to 2020: 1 match\n\
+         3 entries summing to 2020: 1 match\n"
+    );
+}
+
+#[test]
+fn takes_the_target_sizes_and_reducer_from_flags() {
+    // when
+    let output = day_1(
+        "flags",
+        EXAMPLE,
+        &[
+            "--target", "1041", "--size", "2", "--size", "4", "--reduce", "max", "--list",
+        ],
+    );
+
+    // then
+    assert_eq!(
+        output,
+        "[2 entries] 675\n\
+         [4 entries] no 4 entries sum to 1041\n\
+         2 entries summing to 1041: 1 match\n\
+         \x20 lines 3, 5: 366 + 675\n\
+         4 entries summing to 1041: 0 matches\n"
+    );
+}
+
+#[test]
+fn picks_five_of_a_full_expense_report() {
+    // given
+    let input = include_str!("../data/data.txt");
+    assert_eq!(input.lines().count(), 200);
+
+    // when
+    let output = day_1(
+        "five",
+        input,
+        &["--size", "5", "--target", "1000000", "--list"],
+    );
+
+    // then
+    assert_eq!(
+        output,
+        "[5 entries] no 5 entries sum to 1000000\n\
+         5 entries summing to 1000000: 0 matches\n"
+    );
+}
+
+#[test]
+fn picks_the_fewest_entries_with_any_size() {
+    // when
+    let one = day_1("any", EXAMPLE, &["--any-size", "--target", "2801"]);
+    let all = day_1(
+        "all-minimal",
+        "1000\n20\n1000\n1010\n1010\n",
+        &["--any-size", "--all-minimal", "--reduce", "sum"],
+    );
+    let capped = day_1(
+        "max-size",
+        EXAMPLE,
+        &["--any-size", "--target", "3341", "--max-size", "2"],
+    );
+
+    // then
+    assert_eq!(
+        one,
+        "[any size] 521705184\n\
+         \x20 lines 2, 3, 6: 979 + 366 + 1456\n"
+    );
+    assert_eq!(
+        all,
+        "[any size] 2020\n\
+         1 smallest subset of 2 entries summing to 2020\n\
+         \x20 lines 4, 5: 1010 + 1010\n"
+    );
+    assert_eq!(capped, "[any size] no 2 entries or fewer sum to 3341\n");
+}
+
+#[test]
+fn finds_nothing_for_the_most_extreme_targets() {
+    for target in [
+        "--target=-9223372036854775808",
+        "--target=9223372036854775807",
+    ]
+    .iter()
+    {
+        // when
+        let output = day_1("extreme", "1721\n-979\n366\n", &[target, "--size", "2"]);
+
+        // then
+        assert!(
+            output.starts_with("[2 entries] no 2 entries sum to "),
+            "{}",
+            output
+        );
+        assert!(output.ends_with(": 0 matches\n"), "{}", output);
+    }
+}
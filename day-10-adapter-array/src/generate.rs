@@ -0,0 +1,84 @@
+//! Bags of adapters that are 1 or 3 jolts apart, with runs of at most four 1-jolt steps
+//! like the real inputs. Longer runs stop once the number of arrangements would no
+//! longer fit in a `u64`.
+
+use aoc_common::Rng;
+
+/// Arrangements of a run of n 1-jolt steps between two 3-jolt gaps.
+const ARRANGEMENTS: [u64; 5] = [1, 1, 2, 4, 7];
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut adapters = vec![];
+    let mut joltage = 0;
+    let mut run = 0;
+    let mut arrangements = 1u64;
+
+    while adapters.len() < size.max(1) {
+        let grows = arrangements
+            .checked_mul(ARRANGEMENTS[(run + 1).min(4)])
+            .is_some();
+        if run < 4 && grows && rng.chance(2, 3) {
+            joltage += 1;
+            run += 1;
+        } else {
+            arrangements *= ARRANGEMENTS[run];
+            joltage += 3;
+            run = 0;
+        }
+        adapters.push(joltage);
+    }
+
+    rng.shuffle(&mut adapters);
+    adapters.iter().map(|a| format!("{}\n", a)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AdapterArray;
+    use aoc_common::Solution;
+
+    /// Arrangements reaching each adapter, from the ones up to three jolts below.
+    fn arrangements(chain: &[i32]) -> u128 {
+        let mut ways = vec![0u128; chain.len()];
+        ways[0] = 1;
+        for i in 1..chain.len() {
+            ways[i] = (0..i)
+                .filter(|&j| chain[i] - chain[j] <= 3)
+                .map(|j| ways[j])
+                .sum();
+        }
+        ways[chain.len() - 1]
+    }
+
+    #[test]
+    fn generates_chains_of_one_and_three_jolt_steps() {
+        for seed in 0..10 {
+            // given
+            let input = input(&mut Rng::new(seed), 100);
+
+            // when
+            let chain = AdapterArray::parse(&input).unwrap();
+            let combinations = AdapterArray::part2(&chain).unwrap();
+
+            // then
+            assert_eq!(chain.len(), 102);
+            assert!(chain
+                .windows(2)
+                .all(|w| w[1] - w[0] == 1 || w[1] - w[0] == 3));
+            assert_eq!(combinations, arrangements(&chain), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn keeps_arrangements_within_u64() {
+        // given
+        let input = input(&mut Rng::new(0), 2000);
+
+        // when
+        let chain = AdapterArray::parse(&input).unwrap();
+
+        // then
+        assert!(arrangements(&chain) <= u64::MAX as u128);
+    }
+}
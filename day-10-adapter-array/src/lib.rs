@@ -0,0 +1,344 @@
+//! [Day 10: Adapter Array](https://adventofcode.com/2020/day/10): chain every joltage adapter
+//! from the outlet to the device, then count all the chains that would work.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+#[cfg(test)]
+use itertools::Itertools;
+
+mod generate;
+
+/// The puzzle as a [`Solution`]. Parsing sorts the adapters into a chain from the outlet (0)
+/// to the device (3 above the highest adapter).
+pub struct AdapterArray;
+
+impl Solution for AdapterArray {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Adapter Array";
+
+    type Input = Vec<i32>;
+    type Answer1 = usize;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut adapter_chain = aoc_common::parse_lines(input, |l| {
+            let jolts = aoc_common::number::<i32>(Self::DAY, l)?;
+            if jolts < 0 {
+                let offset = l.len() - l.trim_start().len();
+                return Err(Error::parse(
+                    Self::DAY,
+                    l,
+                    offset,
+                    "expected a joltage of 0 or more",
+                ));
+            }
+            Ok(jolts)
+        })?;
+
+        adapter_chain.sort_unstable();
+
+        let device_jolts = adapter_chain
+            .last()
+            .ok_or_else(|| Error::new(Self::DAY, "no adapters in the bag"))?
+            .checked_add(3)
+            .ok_or_else(|| {
+                Error::new(Self::DAY, "the device is rated beyond the largest joltage")
+            })?;
+
+        let mut chain = vec![0];
+        chain.append(&mut adapter_chain);
+        chain.push(device_jolts);
+
+        Ok(chain)
+    }
+
+    fn part1(chain: &Self::Input) -> Result<Self::Answer1> {
+        let one_diffs = chain
+            .windows(2)
+            .filter(|pair| pair[1] - pair[0] == 1)
+            .count();
+        let three_diffs = chain
+            .windows(2)
+            .filter(|pair| pair[1] - pair[0] == 3)
+            .count();
+
+        Ok(one_diffs * three_diffs)
+    }
+
+    fn part2(chain: &Self::Input) -> Result<Self::Answer2> {
+        Ok(count_arrangements(chain))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Ways to get from the outlet to the device, counted adapter by adapter: each one can
+/// follow any of the up to three adapters at most 3 jolts below it.
+pub fn count_arrangements(chain: &[i32]) -> u128 {
+    let mut ways = vec![0u128; chain.len()];
+
+    for index in 0..chain.len() {
+        ways[index] = if index == 0 {
+            1
+        } else {
+            (index.saturating_sub(3)..index)
+                .filter(|&previous| chain[index] - chain[previous] <= 3)
+                .map(|previous| ways[previous])
+                .sum()
+        };
+    }
+
+    ways.last().copied().unwrap_or_default()
+}
+
+/// Brute-force reference for [`count_arrangements`]: splits the chain at 3-jolt gaps
+/// and tries every ordered subset of each chunk.
+#[cfg(test)]
+fn into_chunks(adapters: &[i32]) -> Vec<Vec<i32>> {
+    let mut chunks = Vec::<Vec<i32>>::new();
+    let mut chunk = Vec::<i32>::new();
+
+    let head = &adapters[0];
+    let body = &adapters[1..adapters.len() - 1];
+    let tail = &adapters[adapters.len() - 1];
+
+    chunks.push(vec![head.to_owned()]);
+
+    for adapter in body.iter() {
+        let last_item = chunk.last().cloned();
+        if last_item.is_some() && *adapter == last_item.unwrap() + 3 {
+            chunks.push(chunk.to_owned());
+            chunk.clear();
+        }
+
+        chunk.push(adapter.to_owned());
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(chunk.to_owned())
+    }
+
+    chunks.push(vec![tail.to_owned()]);
+
+    chunks
+}
+
+#[cfg(test)]
+fn count_chunk_combinations(adapters: Vec<Vec<i32>>) -> u128 {
+    adapters
+        .iter()
+        .enumerate()
+        .filter(|(_, chunk)| chunk.len() > 1)
+        .map(|(index, c)| {
+            (1..=c.len())
+                .map(|l| {
+                    let perms = c
+                        .iter()
+                        .permutations(l)
+                        .filter(|p| p.is_sorted())
+                        .filter(|p| {
+                            if index > 0 {
+                                if let Some(prev_adapter) = adapters.get(index - 1) {
+                                    if p.first().unwrap().to_owned()
+                                        - prev_adapter.last().unwrap().to_owned()
+                                        > 3
+                                    {
+                                        return false;
+                                    }
+                                }
+                            }
+
+                            if let Some(next_adapter) = adapters.get(index + 1) {
+                                if next_adapter.first().unwrap().to_owned()
+                                    - p.last().unwrap().to_owned()
+                                    > 3
+                                {
+                                    return false;
+                                }
+                            } else {
+                                let max_val = adapters.last().unwrap().last().unwrap() + 3;
+                                if *p.last().unwrap() > &max_val {
+                                    return false;
+                                }
+                            }
+
+                            true
+                        })
+                        .filter(|p| {
+                            p.windows(2)
+                                .map(|pair| pair[1] - pair[0])
+                                .filter(|p| *p <= 3)
+                                .collect_vec()
+                                .len()
+                                + 1
+                                == p.len()
+                        })
+                        .collect_vec();
+
+                    perms.len() as u128
+                })
+                .sum::<u128>()
+        })
+        .product::<u128>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_2_example() {
+        // given
+        let input = vec![0, 1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19, 22];
+
+        // when
+        let arrangments = count_chunk_combinations(into_chunks(&input));
+
+        // then
+        assert_eq!(arrangments, 8);
+    }
+
+    #[test]
+    fn part_2_example_2() {
+        // given
+        let mut input = vec![
+            28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
+            8, 17, 7, 9, 4, 2, 34, 10, 3, 0, 52,
+        ];
+        input.sort_unstable();
+
+        // when
+        let arrangements = count_chunk_combinations(into_chunks(&input));
+
+        // then
+        assert_eq!(arrangements, 19208);
+    }
+
+    #[test]
+    fn additional_example_1() {
+        // given
+        let mut input = vec![10, 6, 4, 7, 1, 5, 0, 13];
+        input.sort_unstable();
+
+        // when
+        let arrangements = count_chunk_combinations(into_chunks(&input));
+
+        // then
+        assert_eq!(arrangements, 4);
+    }
+
+    #[test]
+    fn additional_example_2() {
+        // given
+        let mut input = vec![4, 11, 7, 8, 1, 6, 5, 0, 14];
+        input.sort_unstable();
+
+        // when
+        let arrangements = count_chunk_combinations(into_chunks(&input));
+
+        // then
+        assert_eq!(arrangements, 7);
+    }
+
+    #[test]
+    fn additional_example_3() {
+        // given
+        let mut input = vec![3, 1, 6, 2, 0, 9];
+        input.sort_unstable();
+
+        // when
+        let arrangements = count_chunk_combinations(into_chunks(&input));
+
+        // then
+        assert_eq!(arrangements, 4);
+    }
+
+    #[test]
+    fn additional_example_4() {
+        // given
+        let mut input = vec![17, 6, 10, 5, 13, 7, 1, 4, 12, 11, 14, 0, 20];
+        input.sort_unstable();
+
+        // when
+        let arrangements = count_chunk_combinations(into_chunks(&input));
+
+        // then
+        assert_eq!(arrangements, 28);
+    }
+
+    #[test]
+    fn reports_empty_bag() {
+        // when
+        let error = AdapterArray::parse("\n").unwrap_err();
+
+        // then
+        assert_eq!(error.message, "no adapters in the bag");
+    }
+
+    #[test]
+    fn reports_invalid_adapter() {
+        // when
+        let error = AdapterArray::parse("16\n10\n-\n").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(3));
+    }
+
+    #[test]
+    fn counts_arrangements_of_the_examples() {
+        // given
+        let small = vec![0, 1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19, 22];
+        let mut large = vec![
+            28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
+            8, 17, 7, 9, 4, 2, 34, 10, 3, 0, 52,
+        ];
+        large.sort_unstable();
+
+        // when
+        let arrangements = (count_arrangements(&small), count_arrangements(&large));
+
+        // then
+        assert_eq!(arrangements, (8, 19208));
+    }
+
+    #[test]
+    fn arrangements_match_the_brute_force_on_generated_bags() {
+        aoc_common::differential::compare(
+            200,
+            |rng| AdapterArray::parse(&generate::input(rng, 40)).unwrap(),
+            |chain| {
+                // Drops adapters in between, as long as the steps stay 1 or 3 jolts.
+                let adapters = &chain[1..chain.len() - 1];
+                aoc_common::differential::shrink_vec(adapters)
+                    .into_iter()
+                    .filter(|adapters| !adapters.is_empty())
+                    .map(|adapters| {
+                        let device = adapters.last().unwrap() + 3;
+                        [&[0], &adapters[..], &[device]].concat()
+                    })
+                    .filter(|chain| {
+                        chain
+                            .windows(2)
+                            .all(|w| w[1] - w[0] == 1 || w[1] - w[0] == 3)
+                    })
+                    .collect()
+            },
+            |chain| count_chunk_combinations(into_chunks(chain)),
+            |chain| count_arrangements(chain),
+        );
+    }
+
+    #[test]
+    fn reports_joltages_out_of_range() {
+        // when
+        let negative = AdapterArray::parse("1\n-4\n").unwrap_err();
+        let largest = AdapterArray::parse(&format!("1\n{}\n", i32::MAX)).unwrap_err();
+
+        // then
+        assert_eq!((negative.line, negative.column), (Some(2), Some(1)));
+        assert!(largest.message.contains("beyond the largest joltage"));
+        assert!(AdapterArray::parse(&format!("{}\n{}\n", i32::MAX - 5, i32::MAX - 3)).is_ok());
+    }
+}
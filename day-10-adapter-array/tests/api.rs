@@ -0,0 +1,20 @@
+use aoc_common::Solution;
+use day_10_adapter_array::{count_arrangements, AdapterArray};
+
+#[test]
+fn chains_adapters_from_outlet_to_device() {
+    // when
+    let chain = AdapterArray::parse("16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n").unwrap();
+
+    // then
+    assert_eq!(chain, [0, 1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19, 22]);
+    assert_eq!(AdapterArray::part1(&chain).unwrap(), 7 * 5);
+    assert_eq!(count_arrangements(&chain), 8);
+}
+
+#[test]
+fn counts_arrangements_of_any_chain() {
+    assert_eq!(count_arrangements(&[0, 3, 6]), 1);
+    assert_eq!(count_arrangements(&[0, 1, 2, 3, 6]), 4);
+    assert_eq!(count_arrangements(&[]), 0);
+}
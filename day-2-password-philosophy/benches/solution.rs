@@ -0,0 +1,13 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_2_password_philosophy::PasswordPhilosophy;
+
+fn benchmark(c: &mut Criterion) {
+    let data = bench::read(aoc_common::data_file!());
+    let generated = bench::generated::<PasswordPhilosophy>(20000);
+
+    bench::solution::<PasswordPhilosophy>(c, &[("data", data), ("generated", generated)]);
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
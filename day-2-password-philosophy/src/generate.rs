@@ -0,0 +1,59 @@
+//! Password database lines, with the policy letter spread so that roughly half of the
+//! passwords pass each policy.
+
+use aoc_common::Rng;
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| line(rng)).collect()
+}
+
+fn letter(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
+
+fn line(rng: &mut Rng) -> String {
+    let low = rng.range(1..=10);
+    let high = rng.range(low + 1..=low + 10);
+    let character = letter(rng);
+    let length = rng.range(high..=high + 4);
+
+    let password = (0..length)
+        .map(|_| {
+            if rng.chance((low + high) as u64 / 2, length as u64) {
+                character
+            } else {
+                letter(rng)
+            }
+        })
+        .collect::<String>();
+
+    format!("{}-{} {}: {}\n", low, high, character, password)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PasswordPhilosophy;
+    use aoc_common::Solution;
+
+    #[test]
+    fn mixes_valid_and_invalid_passwords() {
+        // given
+        let input = input(&mut Rng::new(0), 500);
+
+        // when
+        let passwords = PasswordPhilosophy::parse(&input).unwrap();
+        let sled = PasswordPhilosophy::part1(&passwords).unwrap();
+        let toboggan = PasswordPhilosophy::part2(&passwords).unwrap();
+
+        // then
+        assert_eq!(passwords.len(), 500);
+        assert!((50..450).contains(&sled), "{} valid", sled);
+        assert!((50..450).contains(&toboggan), "{} valid", toboggan);
+    }
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        assert_eq!(input(&mut Rng::new(5), 100), input(&mut Rng::new(5), 100));
+    }
+}
@@ -0,0 +1,239 @@
+//! [Day 2: Password Philosophy](https://adventofcode.com/2020/day/2): count the passwords
+//! that follow their policy, read first as a letter count and then as letter positions.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+mod generate;
+
+#[derive(Debug, Clone, Copy)]
+struct PasswordPolicy {
+    positions: [usize; 2],
+    character: char,
+}
+
+/// A line of the password database, `<a>-<b> <letter>: <password>`, parsed with [`str::parse`].
+#[derive(Debug, Clone)]
+pub struct Password {
+    policy: PasswordPolicy,
+    password: String,
+}
+
+impl FromStr for Password {
+    type Err = Error;
+    fn from_str(password_str: &str) -> Result<Self> {
+        lazy_static! {
+            static ref PASSWORD_REGEX: Regex = Regex::new(r"^(\d+)-(\d+)\s(\w):\s(\w+)$").unwrap();
+        }
+
+        let captures = PASSWORD_REGEX.captures(password_str).ok_or_else(|| {
+            Error::parse(
+                PasswordPhilosophy::DAY,
+                password_str,
+                0,
+                "expected `<min>-<max> <letter>: <password>`",
+            )
+        })?;
+
+        let position = |index: usize| {
+            let capture = captures.get(index).unwrap();
+            capture
+                .as_str()
+                .parse::<usize>()
+                .ok()
+                .filter(|&position| position > 0)
+                .ok_or_else(|| {
+                    Error::parse(
+                        PasswordPhilosophy::DAY,
+                        password_str,
+                        capture.start(),
+                        "expected a position starting from 1",
+                    )
+                })
+        };
+
+        Ok(Password {
+            policy: PasswordPolicy {
+                positions: [position(1)?, position(2)?],
+                character: captures[3].chars().next().unwrap(),
+            },
+            password: captures[4].to_owned(),
+        })
+    }
+}
+
+/// The sled rental policy: the letter occurs between `a` and `b` times.
+pub fn is_valid_sled_rental_password(pass: &Password) -> bool {
+    let char = pass
+        .password
+        .chars()
+        .filter(|c| c == &pass.policy.character)
+        .count();
+
+    char >= pass.policy.positions[0] && char <= pass.policy.positions[1]
+}
+
+/// The Official Toboggan Corporate Policy: the letter is at exactly one of the 1-based
+/// positions `a` and `b`.
+pub fn is_valid_toboggan_password(pass: &Password) -> bool {
+    let left =
+        pass.password.chars().nth(pass.policy.positions[0] - 1) == Some(pass.policy.character);
+    let right =
+        pass.password.chars().nth(pass.policy.positions[1] - 1) == Some(pass.policy.character);
+
+    left ^ right
+}
+
+/// The puzzle as a [`Solution`]: how many passwords are valid under each policy.
+pub struct PasswordPhilosophy;
+
+impl Solution for PasswordPhilosophy {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Password Philosophy";
+
+    type Input = Vec<Password>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_lines(input, str::parse)
+    }
+
+    fn part1(password_list: &Self::Input) -> Result<Self::Answer1> {
+        Ok(password_list
+            .iter()
+            .filter(|p| is_valid_sled_rental_password(p))
+            .count())
+    }
+
+    fn part2(password_list: &Self::Input) -> Result<Self::Answer2> {
+        Ok(password_list
+            .iter()
+            .filter(|p| is_valid_toboggan_password(p))
+            .count())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes_validation_when_min_entries() {
+        // given
+        let password = "1-3 a: abcde".parse::<Password>().unwrap();
+
+        // when
+        let is_valid = is_valid_sled_rental_password(&password);
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn fails_validation_when_no_entires() {
+        // given
+        let password = "1-3 b: cdefg".parse::<Password>().unwrap();
+
+        // when
+        let is_valid = is_valid_sled_rental_password(&password);
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn passes_validation_when_max_entries() {
+        // given
+        let password = "2-9 c: ccccccccc".parse::<Password>().unwrap();
+
+        // when
+        let is_valid = is_valid_sled_rental_password(&password);
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn passes_official_validation_when_single_letter() {
+        // given
+        let password = "1-3 a: abcde".parse::<Password>().unwrap();
+
+        // when
+        let is_valid = is_valid_toboggan_password(&password);
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn fails_official_validation_when_no_letters() {
+        // given
+        let password = "1-3 b: cdefg".parse::<Password>().unwrap();
+
+        // when
+        let is_valid = is_valid_toboggan_password(&password);
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn fails_official_validation_when_letters_are_duplicated() {
+        // given
+        let password = "2-9 c: ccccccccc".parse::<Password>().unwrap();
+
+        // when
+        let is_valid = is_valid_toboggan_password(&password);
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn fails_to_parse_a_password_without_policy() {
+        // when
+        let error = "abcde".parse::<Password>().unwrap_err();
+
+        // then
+        assert_eq!(error.column, Some(1));
+        assert_eq!(error.text, "abcde");
+    }
+
+    #[test]
+    fn fails_to_parse_a_zero_position() {
+        // when
+        let error = "1-0 a: abcde".parse::<Password>().unwrap_err();
+
+        // then
+        assert_eq!(error.column, Some(3));
+    }
+
+    #[test]
+    fn passes_official_validation_when_other_position_is_past_the_end() {
+        // given
+        let password = "1-9 a: abc".parse::<Password>().unwrap();
+
+        // when
+        let is_valid = is_valid_toboggan_password(&password);
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn fuzzed_passwords_never_panics() {
+        let seeds = aoc_common::lines(include_str!("../data/data.txt")).collect::<Vec<_>>();
+
+        aoc_common::fuzz::fuzz(&seeds, |input| {
+            let _ = input.parse::<Password>();
+        });
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::Solution;
+use day_2_password_philosophy::{
+    is_valid_sled_rental_password, is_valid_toboggan_password, Password, PasswordPhilosophy,
+};
+
+#[test]
+fn checks_both_policies() {
+    // given
+    let passwords = ["1-3 a: abcde", "1-3 b: cdefg", "2-9 c: ccccccccc"]
+        .iter()
+        .map(|line| line.parse::<Password>().unwrap())
+        .collect::<Vec<_>>();
+
+    // when
+    let sled_rental = passwords.iter().map(is_valid_sled_rental_password);
+    let toboggan = passwords.iter().map(is_valid_toboggan_password);
+
+    // then
+    assert_eq!(sled_rental.collect::<Vec<_>>(), [true, false, true]);
+    assert_eq!(toboggan.collect::<Vec<_>>(), [true, false, false]);
+}
+
+#[test]
+fn rejects_malformed_lines() {
+    // when
+    let error = PasswordPhilosophy::parse("1-3 a: abcde\n0-3 b: cdefg\n").unwrap_err();
+
+    // then
+    assert_eq!(error.line, Some(2));
+    assert_eq!(error.column, Some(1));
+}
@@ -0,0 +1,13 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_3_toboggan_trajectory::TobogganTrajectory;
+
+fn benchmark(c: &mut Criterion) {
+    let data = bench::read(aoc_common::data_file!());
+    let generated = bench::generated::<TobogganTrajectory>(6000);
+
+    bench::solution::<TobogganTrajectory>(c, &[("data", data), ("generated", generated)]);
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Forest maps 31 squares wide, like the real ones, with a tree on about a quarter
+//! of the squares.
+
+use aoc_common::Rng;
+
+const WIDTH: usize = 31;
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut row = (0..WIDTH)
+                .map(|_| if rng.chance(1, 4) { '#' } else { '.' })
+                .collect::<String>();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TobogganTrajectory;
+    use aoc_common::Solution;
+
+    #[test]
+    fn generates_a_map_with_trees_on_every_slope() {
+        // given
+        let input = input(&mut Rng::new(0), 400);
+
+        // when
+        let map = TobogganTrajectory::parse(&input).unwrap();
+        let trees = TobogganTrajectory::part1(&map).unwrap();
+        let product = TobogganTrajectory::part2(&map).unwrap();
+
+        // then
+        assert_eq!(input.lines().count(), 400);
+        assert!(input.lines().all(|row| row.len() == WIDTH));
+        assert!((50..150).contains(&trees), "{} trees", trees);
+        assert!(product > 0);
+    }
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        assert_eq!(input(&mut Rng::new(5), 100), input(&mut Rng::new(5), 100));
+    }
+}
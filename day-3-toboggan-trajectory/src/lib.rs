@@ -0,0 +1,247 @@
+//! [Day 3: Toboggan Trajectory](https://adventofcode.com/2020/day/3): count the trees hit
+//! while sliding down a map that repeats to the right.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use std::str::FromStr;
+
+mod generate;
+
+#[derive(Debug, PartialEq)]
+enum MapEntry {
+    Tree,
+    Blank,
+}
+
+impl FromStr for MapEntry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "." => Ok(MapEntry::Blank),
+            "#" => Ok(MapEntry::Tree),
+            _ => Err(Error::parse(
+                TobogganTrajectory::DAY,
+                s,
+                0,
+                "expected `.` or `#`",
+            )),
+        }
+    }
+}
+
+/// Rows of open squares (`.`) and trees (`#`), repeating endlessly to the right.
+#[derive(Debug)]
+pub struct ForestMap {
+    rows: Vec<Vec<MapEntry>>,
+}
+
+impl ForestMap {
+    /// Reads one row per line, ignoring indentation and blank lines around the map; blank
+    /// lines within it are errors.
+    pub fn parse(raw_map: &str) -> Result<ForestMap> {
+        let lines = raw_map.lines().collect::<Vec<_>>();
+        let is_row = |line: &&str| !line.trim().is_empty();
+        let first = lines.iter().position(is_row).unwrap_or(lines.len());
+        let last = lines
+            .iter()
+            .rposition(is_row)
+            .map_or(first, |last| last + 1);
+
+        let rows = lines[first..last]
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                let index = first + row;
+                let indent = line.len() - line.trim_start().len();
+                if line.trim().is_empty() {
+                    return Err(Error::parse(
+                        TobogganTrajectory::DAY,
+                        line,
+                        0,
+                        "expected a row of `.` and `#`",
+                    )
+                    .on_line(index + 1));
+                }
+
+                line.trim()
+                    .char_indices()
+                    .map(|(offset, entry)| {
+                        entry.to_string().parse::<MapEntry>().map_err(|e| {
+                            Error::parse(e.day, line, indent + offset, e.message).on_line(index + 1)
+                        })
+                    })
+                    .collect::<Result<Vec<MapEntry>>>()
+            })
+            .collect::<Result<Vec<Vec<MapEntry>>>>()?;
+
+        Ok(ForestMap { rows })
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether there is a tree at column `x` (wrapping around) of row `y`, which is never
+    /// the case below the last row.
+    pub fn is_tree(&self, x: usize, y: usize) -> bool {
+        if let Some(row) = self.rows.get(y) {
+            if let Some(entry) = x.checked_rem(row.len()).and_then(|x| row.get(x)) {
+                return entry == &MapEntry::Tree;
+            }
+        }
+
+        false
+    }
+}
+
+/// Trees hit going from the top left corner `delta_x` right and `delta_y` down at a time.
+///
+/// # Panics
+///
+/// When `delta_y` is 0, as the toboggan would never reach the bottom.
+pub fn count_trees_for_slope(map: &ForestMap, delta_x: usize, delta_y: usize) -> usize {
+    std::iter::repeat(0)
+        .enumerate()
+        .map(|(index, _)| index * delta_x)
+        .zip((0..map.height()).step_by(delta_y))
+        .filter(|(x, y)| map.is_tree(x.to_owned(), y.to_owned()))
+        .count()
+}
+
+/// The puzzle as a [`Solution`]: trees on the 3-right 1-down slope, then the product
+/// over five slopes.
+pub struct TobogganTrajectory;
+
+impl Solution for TobogganTrajectory {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+
+    type Input = ForestMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        ForestMap::parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Self::Answer1> {
+        Ok(count_trees_for_slope(map, 3, 1))
+    }
+
+    fn part2(map: &Self::Input) -> Result<Self::Answer2> {
+        Ok([
+            count_trees_for_slope(map, 1, 1),
+            count_trees_for_slope(map, 3, 1),
+            count_trees_for_slope(map, 5, 1),
+            count_trees_for_slope(map, 7, 1),
+            count_trees_for_slope(map, 1, 2),
+        ]
+        .iter()
+        .product::<usize>())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_trees_for_example_map() {
+        // given
+        let map = ForestMap::parse(
+            "
+            ..##.......
+            #...#...#..
+            .#....#..#.
+            ..#.#...#.#
+            .#...##..#.
+            ..#.##.....
+            .#.#.#....#
+            .#........#
+            #.##...#...
+            #...##....#
+            .#..#...#.#
+        "
+            .trim(),
+        )
+        .unwrap();
+
+        // when
+        let trees = count_trees_for_slope(&map, 3, 1);
+
+        // then
+        assert_eq!(trees, 7);
+    }
+
+    #[test]
+    fn reports_unknown_map_entry() {
+        // when
+        let error = ForestMap::parse("..##\n#.x.\n").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(3));
+        assert_eq!(error.text, "#.x.");
+    }
+
+    #[test]
+    fn parses_map_with_trailing_newline() {
+        // when
+        let map = ForestMap::parse("..#\n#..\n").unwrap();
+
+        // then
+        assert_eq!(map.height(), 2);
+        assert!(map.is_tree(3, 1));
+    }
+
+    #[test]
+    fn reports_empty_row() {
+        // when
+        let error = ForestMap::parse("..#\n\n#..").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn finds_no_trees_in_empty_row() {
+        // given
+        let map = ForestMap { rows: vec![vec![]] };
+
+        // when
+        let is_tree = map.is_tree(3, 0);
+
+        // then
+        assert!(!is_tree);
+    }
+
+    #[test]
+    fn fuzzed_maps_never_panics() {
+        let rows = include_str!("../data/data.txt").lines().collect::<Vec<_>>();
+        let seeds = rows
+            .chunks(8)
+            .map(|chunk| chunk.join("\n"))
+            .collect::<Vec<_>>();
+        let seeds = seeds.iter().map(String::as_str).collect::<Vec<_>>();
+
+        aoc_common::fuzz::fuzz(&seeds, |input| {
+            let _ = ForestMap::parse(input);
+        });
+    }
+
+    #[test]
+    fn reports_the_file_line_after_leading_blank_lines() {
+        // when
+        let error = TobogganTrajectory::parse("\n\n..#\n.x.\n\n").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(4));
+        assert_eq!(error.column, Some(2));
+    }
+}
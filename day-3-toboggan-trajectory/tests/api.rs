@@ -0,0 +1,42 @@
+use day_3_toboggan_trajectory::{count_trees_for_slope, ForestMap};
+
+const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+#[test]
+fn reads_the_map() {
+    // when
+    let map = ForestMap::parse(EXAMPLE).unwrap();
+
+    // then
+    assert_eq!(map.height(), 11);
+    assert!(map.is_tree(2, 0));
+    assert!(map.is_tree(2 + 11, 0));
+    assert!(!map.is_tree(0, 0));
+    assert!(!map.is_tree(0, 11));
+}
+
+#[test]
+fn counts_trees_for_every_slope() {
+    // given
+    let map = ForestMap::parse(EXAMPLE).unwrap();
+
+    // when
+    let trees = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|&(right, down)| count_trees_for_slope(&map, right, down))
+        .collect::<Vec<_>>();
+
+    // then
+    assert_eq!(trees, [2, 7, 3, 4, 2]);
+}
@@ -0,0 +1,13 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_4_passport_processing::PassportProcessing;
+
+fn benchmark(c: &mut Criterion) {
+    let data = bench::read(aoc_common::data_file!());
+    let generated = bench::generated::<PassportProcessing>(5000);
+
+    bench::solution::<PassportProcessing>(c, &[("data", data), ("generated", generated)]);
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -0,0 +1,138 @@
+//! Passport batches. Every field is missing now and then (`cid` half of the time),
+//! and every value is out of spec now and then, so both parts reject some passports.
+
+use aoc_common::Rng;
+
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| passport(rng))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn passport(rng: &mut Rng) -> String {
+    let mut fields = vec![
+        ("byr", year(rng, 1920, 2002)),
+        ("iyr", year(rng, 2010, 2020)),
+        ("eyr", year(rng, 2020, 2030)),
+        ("hgt", height(rng)),
+        ("hcl", hair_color(rng)),
+        ("ecl", eye_color(rng)),
+        ("pid", passport_id(rng)),
+    ];
+    fields.retain(|_| !rng.chance(1, 20));
+    if rng.chance(1, 2) {
+        fields.push(("cid", rng.range(100..=350).to_string()));
+    }
+    rng.shuffle(&mut fields);
+
+    let mut passport = String::new();
+    for (index, (field, value)) in fields.iter().enumerate() {
+        if index > 0 {
+            passport.push(if rng.chance(1, 3) { '\n' } else { ' ' });
+        }
+        passport.push_str(&format!("{}:{}", field, value));
+    }
+    passport.push('\n');
+    passport
+}
+
+fn out_of_spec(rng: &mut Rng) -> bool {
+    rng.chance(1, 12)
+}
+
+fn year(rng: &mut Rng, first: i64, last: i64) -> String {
+    if !out_of_spec(rng) {
+        return rng.range(first..=last).to_string();
+    }
+
+    match rng.below(3) {
+        0 => rng.range(first - 30..=first - 1).to_string(),
+        1 => rng.range(last + 1..=last + 30).to_string(),
+        _ => rng.range(10..=99).to_string(),
+    }
+}
+
+fn height(rng: &mut Rng) -> String {
+    let metric = rng.chance(1, 2);
+    if !out_of_spec(rng) {
+        return if metric {
+            format!("{}cm", rng.range(150..=193))
+        } else {
+            format!("{}in", rng.range(59..=76))
+        };
+    }
+
+    match rng.below(3) {
+        0 => format!("{}cm", rng.range(100..=149)),
+        1 => format!("{}in", rng.range(77..=99)),
+        _ => rng.range(59..=193).to_string(),
+    }
+}
+
+fn hex(rng: &mut Rng, digits: usize) -> String {
+    (0..digits)
+        .map(|_| std::char::from_digit(rng.below(16) as u32, 16).unwrap())
+        .collect()
+}
+
+fn hair_color(rng: &mut Rng) -> String {
+    if !out_of_spec(rng) {
+        return format!("#{}", hex(rng, 6));
+    }
+
+    match rng.below(2) {
+        0 => hex(rng, 6),
+        _ => format!("#{}z", hex(rng, 5)),
+    }
+}
+
+fn eye_color(rng: &mut Rng) -> String {
+    if !out_of_spec(rng) {
+        return rng.pick(&EYE_COLORS).to_string();
+    }
+
+    rng.pick(&["xry", "zzz", "blk", "gmt", "amber"]).to_string()
+}
+
+fn passport_id(rng: &mut Rng) -> String {
+    let digits = if out_of_spec(rng) {
+        *rng.pick(&[8, 10])
+    } else {
+        9
+    };
+
+    (0..digits)
+        .map(|_| std::char::from_digit(rng.below(10) as u32, 10).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PassportProcessing;
+    use aoc_common::Solution;
+
+    #[test]
+    fn mixes_valid_and_invalid_passports() {
+        // given
+        let input = input(&mut Rng::new(0), 500);
+
+        // when
+        let passports = PassportProcessing::parse(&input).unwrap();
+        let complete = PassportProcessing::part1(&passports).unwrap();
+        let valid = PassportProcessing::part2(&passports).unwrap();
+
+        // then
+        assert_eq!(passports.len(), 500);
+        assert!((100..500).contains(&complete), "{} complete", complete);
+        assert!((50..complete).contains(&valid), "{} valid", valid);
+    }
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        assert_eq!(input(&mut Rng::new(5), 100), input(&mut Rng::new(5), 100));
+    }
+}
@@ -0,0 +1,314 @@
+//! [Day 4: Passport Processing](https://adventofcode.com/2020/day/4): count the passports
+//! with every required field, then those whose fields also hold valid values.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+mod generate;
+
+/// `key:value` fields separated by spaces or newlines, parsed with [`str::parse`].
+#[derive(Debug)]
+pub struct Passport {
+    fields: Vec<(String, String)>,
+}
+
+impl Passport {
+    /// Value of the first field called `key`.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Whether all eight fields are there, except possibly `cid`.
+    pub fn is_valid(&self) -> bool {
+        if self.fields.len() == 8 {
+            return true;
+        }
+        if self.fields.len() == 7
+            && self
+                .fields
+                .iter()
+                .find(|(field, _)| field == "cid")
+                .is_none()
+        {
+            return true;
+        }
+
+        false
+    }
+
+    /// Whether the passport [is valid](Passport::is_valid) and every field is within the rules:
+    /// birth, issue and expiration years, height in cm or in, hair colour, eye colour and a
+    /// nine digit passport id.
+    pub fn is_strictly_valid(&self) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+
+        let as_hash: HashMap<String, String> = self.fields.iter().cloned().collect();
+
+        lazy_static! {
+            static ref BYR_REGEX: Regex = Regex::new(r"^(19[2-9]\d|200[0-2])$").unwrap();
+        }
+        if !as_hash.get("byr").is_some_and(|v| BYR_REGEX.is_match(v)) {
+            return false;
+        }
+
+        lazy_static! {
+            static ref IYR_REGEX: Regex = Regex::new(r"^(201\d|2020)$").unwrap();
+        }
+        if !as_hash.get("iyr").is_some_and(|v| IYR_REGEX.is_match(v)) {
+            return false;
+        }
+
+        lazy_static! {
+            static ref EYR_REGEX: Regex = Regex::new(r"^(202\d|2030)$").unwrap();
+        }
+        if !as_hash.get("eyr").is_some_and(|v| EYR_REGEX.is_match(v)) {
+            return false;
+        }
+
+        lazy_static! {
+            static ref HGT_REGEX: Regex =
+                Regex::new(r"^((1[5-8]\d|19[0-3])cm)|(([5-6]\d|7[0-6])in)$").unwrap();
+        }
+        if !as_hash.get("hgt").is_some_and(|v| HGT_REGEX.is_match(v)) {
+            return false;
+        }
+
+        lazy_static! {
+            static ref HCL_REGEX: Regex = Regex::new(r"^#[a-f0-9]{6}$").unwrap();
+        }
+        if !as_hash.get("hcl").is_some_and(|v| HCL_REGEX.is_match(v)) {
+            return false;
+        }
+
+        lazy_static! {
+            static ref ECL_REGEX: Regex = Regex::new(r"^(amb|blu|brn|gry|grn|hzl|oth)$").unwrap();
+        }
+        if !as_hash.get("ecl").is_some_and(|v| ECL_REGEX.is_match(v)) {
+            return false;
+        }
+
+        lazy_static! {
+            static ref PID_REGEX: Regex = Regex::new(r"^([0-9]{9})$").unwrap();
+        }
+        if !as_hash.get("pid").is_some_and(|v| PID_REGEX.is_match(v)) {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl FromStr for Passport {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let fields = s
+            .split_whitespace()
+            .map(|slice| {
+                let mut parts = slice.splitn(2, ':');
+
+                match (parts.next(), parts.next()) {
+                    (Some(field), Some(value)) => Ok((field.to_owned(), value.to_owned())),
+                    _ => Err(Error::parse(
+                        PassportProcessing::DAY,
+                        s,
+                        slice.as_ptr() as usize - s.as_ptr() as usize,
+                        "expected `field:value`",
+                    )),
+                }
+            })
+            .collect::<Result<Vec<(String, String)>>>()?;
+
+        Ok(Passport { fields })
+    }
+}
+
+/// The puzzle as a [`Solution`]: how many passports are valid, then strictly valid.
+pub struct PassportProcessing;
+
+impl Solution for PassportProcessing {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Passport Processing";
+
+    type Input = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_records(input, str::parse)
+    }
+
+    fn part1(passports: &Self::Input) -> Result<Self::Answer1> {
+        Ok(passports
+            .iter()
+            .filter(|passport| passport.is_valid())
+            .count())
+    }
+
+    fn part2(passports: &Self::Input) -> Result<Self::Answer2> {
+        Ok(passports
+            .iter()
+            .filter(|passport| passport.is_strictly_valid())
+            .count())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_passport_1() {
+        // given
+        let passport = "eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926"
+            .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn invalid_passport_2() {
+        // given
+        let passport = "iyr:2019 hcl:#602927 eyr:1967 hgt:170cm ecl:grn pid:012533040 byr:1946"
+            .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn invalid_passport_3() {
+        // given
+        let passport =
+            "hcl:dab227 iyr:2012 ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277"
+                .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn invalid_passport_4() {
+        // given
+        let passport = "hgt:59cm ecl:zzz eyr:2038 hcl:74454a iyr:2023 pid:3556412378 byr:2007"
+            .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn valid_passport_1() {
+        // given
+        let passport = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f"
+            .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn valid_passport_2() {
+        // given
+        let passport =
+            "eyr:2029 ecl:blu cid:129 byr:1989 iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm"
+                .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn valid_passport_3() {
+        // given
+        let passport =
+            "hcl:#888785 hgt:164cm byr:2001 iyr:2015 cid:88 pid:545766238 ecl:hzl eyr:2022"
+                .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn valid_passport_4() {
+        // given
+        let passport = "iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719"
+            .parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn reports_field_without_value() {
+        // given
+        let input = "ecl:gry pid:860033327\n\niyr:2013 ecl:amb\ncid:350 eyr2023";
+
+        // when
+        let error = PassportProcessing::parse(input).unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(4));
+        assert_eq!(error.column, Some(9));
+        assert_eq!(error.text, "cid:350 eyr2023");
+    }
+
+    #[test]
+    fn invalid_passport_with_repeated_field() {
+        // given
+        let passport =
+            "byr:1980 byr:1981 iyr:2012 eyr:2030 hgt:74in hcl:#623a2f ecl:grn".parse::<Passport>();
+
+        // when
+        let is_valid = passport.unwrap().is_strictly_valid();
+
+        // then
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn fuzzed_passports_never_panics() {
+        let seeds = aoc_common::records(include_str!("../data/data.txt")).collect::<Vec<_>>();
+
+        aoc_common::fuzz::fuzz(&seeds, |input| {
+            let _ = input.parse::<Passport>();
+        });
+    }
+}
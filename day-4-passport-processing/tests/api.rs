@@ -0,0 +1,36 @@
+use aoc_common::Solution;
+use day_4_passport_processing::{Passport, PassportProcessing};
+
+#[test]
+fn reads_fields_across_lines() {
+    // when
+    let passport =
+        "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\nbyr:1937 iyr:2017 cid:147 hgt:183cm"
+            .parse::<Passport>()
+            .unwrap();
+
+    // then
+    assert_eq!(passport.field("hgt"), Some("183cm"));
+    assert_eq!(passport.field("xyz"), None);
+    assert!(passport.is_valid());
+    assert!(passport.is_strictly_valid());
+}
+
+#[test]
+fn tells_present_fields_from_valid_ones() {
+    // given
+    let passports = PassportProcessing::parse(
+        "eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019 hcl:#602927 eyr:1967 hgt:170cm ecl:grn pid:012533040 byr:1946
+
+hcl:#888785 hgt:164cm byr:2001 iyr:2015 cid:88 pid:545766238 ecl:hzl eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021",
+    )
+    .unwrap();
+
+    // then
+    assert_eq!(PassportProcessing::part1(&passports).unwrap(), 3);
+    assert_eq!(PassportProcessing::part2(&passports).unwrap(), 1);
+}
@@ -0,0 +1,65 @@
+//! Boarding passes for a block of consecutive seats with a single one missing,
+//! yours, somewhere in the middle.
+
+use aoc_common::Rng;
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(3, 1000) as i64;
+    let first = rng.range(1..=1023 - count);
+    let missing = rng.range(first + 1..=first + count - 1);
+
+    let mut ids = (first..=first + count)
+        .filter(|&id| id != missing)
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut ids);
+
+    ids.iter()
+        .map(|&id| format!("{}\n", boarding_pass(id)))
+        .collect()
+}
+
+fn boarding_pass(id: i64) -> String {
+    (0..10)
+        .map(|bit| {
+            let set = id >> (9 - bit) & 1 == 1;
+            match (bit < 7, set) {
+                (true, false) => 'F',
+                (true, true) => 'B',
+                (false, false) => 'L',
+                (false, true) => 'R',
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BinaryBoarding;
+    use aoc_common::Solution;
+
+    #[test]
+    fn encodes_seat_ids() {
+        assert_eq!(boarding_pass(357), "FBFBBFFRLR");
+        assert_eq!(boarding_pass(820), "BBFFBBFRLL");
+    }
+
+    #[test]
+    fn leaves_exactly_one_seat_free() {
+        for seed in 0..20 {
+            // given
+            let input = input(&mut Rng::new(seed), 200);
+
+            // when
+            let ids = BinaryBoarding::parse(&input).unwrap();
+            let free = BinaryBoarding::part2(&ids).unwrap();
+
+            // then
+            let (min, max) = (*ids.iter().min().unwrap(), *ids.iter().max().unwrap());
+            assert_eq!(ids.len(), 200);
+            assert_eq!(max - min, 200);
+            assert!(!ids.contains(&free));
+            assert!(min < free && free < max);
+        }
+    }
+}
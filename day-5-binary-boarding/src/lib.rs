@@ -0,0 +1,168 @@
+//! [Day 5: Binary Boarding](https://adventofcode.com/2020/day/5): decode boarding passes
+//! into seats, then find the one free seat between them.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use std::str::FromStr;
+
+mod generate;
+
+/// A seat on the plane, parsed with [`str::parse`] from a boarding pass like `FBFBBFFRLR`.
+#[derive(Debug)]
+pub struct Seat {
+    /// Row from 0 at the front to 127 at the back.
+    pub row: usize,
+    /// Column from 0 on the left to 7 on the right.
+    pub column: usize,
+}
+
+impl Seat {
+    /// Decodes `F`/`B` halving the rows and `L`/`R` halving the columns, ignoring any
+    /// other character. Parsing the pass with [`str::parse`] checks it first.
+    pub fn parse_from_binary_spec(spec: Vec<char>) -> Seat {
+        let (row, cols) = spec
+            .iter()
+            .fold(((0..127), (0..7)), |(rows, cols), &m| match m {
+                'F' => ((rows.start..rows.end - (rows.len() / 2 + 1)), cols),
+                'B' => ((rows.start + (rows.len() / 2 + 1)..rows.end), cols),
+                'L' => (rows, (cols.start..cols.end - (cols.len() / 2 + 1))),
+                'R' => (rows, (cols.start + ((cols.len() / 2) + 1)..cols.end)),
+                _ => (rows, cols),
+            });
+
+        Seat {
+            row: row.start,
+            column: cols.start,
+        }
+    }
+
+    /// The seat id, row times 8 plus column.
+    pub fn get_id(&self) -> usize {
+        self.row * 8 + self.column
+    }
+}
+
+impl FromStr for Seat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let spec = s.chars().collect::<Vec<char>>();
+
+        let misplaced = s.char_indices().enumerate().find(|(index, (_, c))| {
+            let expected = if *index < 7 { ['F', 'B'] } else { ['L', 'R'] };
+            !expected.contains(c)
+        });
+
+        if let Some((_, (offset, _))) = misplaced {
+            return Err(Error::parse(
+                BinaryBoarding::DAY,
+                s,
+                offset,
+                "expected `F` or `B` for the row and `L` or `R` for the column",
+            ));
+        }
+        if spec.len() != 10 {
+            return Err(Error::parse(
+                BinaryBoarding::DAY,
+                s,
+                s.len(),
+                "expected 7 row and 3 column characters",
+            ));
+        }
+
+        Ok(Seat::parse_from_binary_spec(spec))
+    }
+}
+
+/// The puzzle as a [`Solution`]: the highest seat id, then the id of the free seat.
+pub struct BinaryBoarding;
+
+impl Solution for BinaryBoarding {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Binary Boarding";
+
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_lines(input, |s| s.parse::<Seat>().map(|seat| seat.get_id()))
+    }
+
+    fn part1(seat_ids: &Self::Input) -> Result<Self::Answer1> {
+        seat_ids
+            .iter()
+            .max()
+            .copied()
+            .ok_or_else(|| Error::new(Self::DAY, "no boarding passes"))
+    }
+
+    fn part2(seat_ids: &Self::Input) -> Result<Self::Answer2> {
+        let min_id = seat_ids.iter().min().copied().unwrap_or_default();
+        let max_id = seat_ids.iter().max().copied().unwrap_or_default();
+
+        (min_id..max_id)
+            .find(|id| !seat_ids.contains(id))
+            .ok_or_else(|| Error::new(Self::DAY, "no free seat between the boarding passes"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_seat_id() {
+        // given
+        let seat = "FBFBBFFRLR".parse::<Seat>().unwrap();
+
+        // when
+        let id = seat.get_id();
+
+        // then
+        assert_eq!(id, 357);
+    }
+
+    #[test]
+    fn finds_the_missing_seat() {
+        // given
+        let seat_ids = vec![3, 7, 4, 6];
+
+        // when
+        let seat = BinaryBoarding::part2(&seat_ids).unwrap();
+
+        // then
+        assert_eq!(seat, 5);
+    }
+
+    #[test]
+    fn reports_invalid_seat_character() {
+        // when
+        let error = BinaryBoarding::parse("BFFFBBFRRR\nFFFBXBBRRR\n").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(5));
+    }
+
+    #[test]
+    fn reports_truncated_seat() {
+        // when
+        let error = "FFFBBBFRR".parse::<Seat>().unwrap_err();
+
+        // then
+        assert_eq!(error.column, Some(10));
+    }
+
+    #[test]
+    fn fuzzed_seats_never_panics() {
+        let seeds = aoc_common::lines(include_str!("../data/data.txt")).collect::<Vec<_>>();
+
+        aoc_common::fuzz::fuzz(&seeds, |input| {
+            let _ = input.parse::<Seat>();
+        });
+    }
+}
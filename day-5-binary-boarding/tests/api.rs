@@ -0,0 +1,29 @@
+use aoc_common::Solution;
+use day_5_binary_boarding::{BinaryBoarding, Seat};
+
+#[test]
+fn decodes_boarding_passes() {
+    for (pass, row, column, id) in [
+        ("BFFFBBFRRR", 70, 7, 567),
+        ("FFFBBBFRRR", 14, 7, 119),
+        ("BBFFBBFRLL", 102, 4, 820),
+    ]
+    .iter()
+    {
+        // when
+        let seat = pass.parse::<Seat>().unwrap();
+
+        // then
+        assert_eq!((seat.row, seat.column, seat.get_id()), (*row, *column, *id));
+    }
+}
+
+#[test]
+fn finds_the_free_seat() {
+    // given
+    let seats = BinaryBoarding::parse("FFFFFFFLLL\nFFFFFFFLLR\nFFFFFFFLRR\n").unwrap();
+
+    // then
+    assert_eq!(BinaryBoarding::part1(&seats).unwrap(), 3);
+    assert_eq!(BinaryBoarding::part2(&seats).unwrap(), 2);
+}
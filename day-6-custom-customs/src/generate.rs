@@ -0,0 +1,72 @@
+//! Customs groups of one to five people. Each group shares a few answers everyone gave,
+//! and each person adds some of their own.
+
+use aoc_common::Rng;
+use std::ops::RangeInclusive;
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| group(rng))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn questions(rng: &mut Rng, count: RangeInclusive<i64>) -> Vec<char> {
+    let count = rng.range(count);
+    let mut letters = ('a'..='z').collect::<Vec<_>>();
+    rng.shuffle(&mut letters);
+    letters.truncate(count as usize);
+    letters
+}
+
+fn group(rng: &mut Rng) -> String {
+    let shared = questions(rng, 0..=3);
+    let people = rng.range(1..=5);
+
+    (0..people)
+        .map(|_| {
+            let mut answers = shared.clone();
+            for question in questions(rng, 0..=8) {
+                if !answers.contains(&question) {
+                    answers.push(question);
+                }
+            }
+            if answers.is_empty() {
+                answers = questions(rng, 1..=1);
+            }
+            rng.shuffle(&mut answers);
+
+            answers.into_iter().chain(Some('\n')).collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CustomCustoms;
+    use aoc_common::Solution;
+
+    #[test]
+    fn generates_groups_with_shared_answers() {
+        // given
+        let input = input(&mut Rng::new(0), 300);
+
+        // when
+        let groups = CustomCustoms::parse(&input).unwrap();
+        let anyone = CustomCustoms::part1(&groups).unwrap();
+        let everyone = CustomCustoms::part2(&groups).unwrap();
+
+        // then
+        assert_eq!(groups.len(), 300);
+        assert!(input
+            .lines()
+            .all(|line| line.is_empty() || line.len() <= 26));
+        assert!(everyone > 0 && everyone < anyone);
+    }
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        assert_eq!(input(&mut Rng::new(5), 100), input(&mut Rng::new(5), 100));
+    }
+}
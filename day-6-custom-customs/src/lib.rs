@@ -0,0 +1,126 @@
+//! [Day 6: Custom Customs](https://adventofcode.com/2020/day/6): add up the questions each
+//! group answered "yes" to, first by anyone in it and then by everyone.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use std::collections::HashSet;
+
+mod generate;
+
+/// Questions that at least one person of the group, one per line, answered.
+pub fn count_answered_by_anyone(group: &str) -> usize {
+    let mut chars = group
+        .lines()
+        .collect::<Vec<&str>>()
+        .join("")
+        .chars()
+        .collect::<Vec<char>>();
+    chars.sort_unstable();
+    chars.dedup();
+
+    chars.len()
+}
+
+/// Questions that every person of the group answered.
+pub fn count_answered_by_everyone(group: &str) -> usize {
+    group
+        .lines()
+        .map(|l| l.chars().collect::<HashSet<char>>())
+        .reduce(|acc, set| acc.intersection(&set).copied().collect())
+        .into_iter()
+        .map(|s| s.len())
+        .sum()
+}
+
+/// Checks that a group holds nothing but the question letters `a` to `z`, one person per line.
+pub fn parse_group(group: &str) -> Result<String> {
+    match group.find(|c: char| !c.is_ascii_lowercase() && c != '\n') {
+        Some(offset) => Err(Error::parse(
+            CustomCustoms::DAY,
+            group,
+            offset,
+            "expected question letters `a` to `z`",
+        )),
+        None => Ok(group.to_owned()),
+    }
+}
+
+/// The puzzle as a [`Solution`]: the sums of questions answered by anyone, then by everyone.
+pub struct CustomCustoms;
+
+impl Solution for CustomCustoms {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Custom Customs";
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_records(input, parse_group)
+    }
+
+    fn part1(groups: &Self::Input) -> Result<Self::Answer1> {
+        Ok(groups.iter().map(|g| count_answered_by_anyone(g)).sum())
+    }
+
+    fn part2(groups: &Self::Input) -> Result<Self::Answer2> {
+        Ok(groups.iter().map(|g| count_answered_by_everyone(g)).sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb";
+
+    #[test]
+    fn part1_example() {
+        // given
+        let groups = CustomCustoms::parse(EXAMPLE).unwrap();
+
+        // when
+        let sum = CustomCustoms::part1(&groups).unwrap();
+
+        // then
+        assert_eq!(sum, 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        // given
+        let groups = CustomCustoms::parse(EXAMPLE).unwrap();
+
+        // when
+        let sum = CustomCustoms::part2(&groups).unwrap();
+
+        // then
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn reports_invalid_answer() {
+        // when
+        let error = CustomCustoms::parse("abc\n\na\nB\nc").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(4));
+        assert_eq!(error.column, Some(1));
+        assert_eq!(error.text, "B");
+    }
+
+    #[test]
+    fn rejects_whitespace_within_a_line() {
+        // when
+        let error = CustomCustoms::parse("ab\n c\n\nab\nab c\n").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(1));
+    }
+}
@@ -0,0 +1,24 @@
+use day_6_custom_customs::{count_answered_by_anyone, count_answered_by_everyone, parse_group};
+
+#[test]
+fn counts_answers_per_group() {
+    // given
+    let groups = ["abc", "a\nb\nc", "ab\nac", "a\na\na\na", "b"];
+
+    // when
+    let anyone = groups.iter().map(|g| count_answered_by_anyone(g));
+    let everyone = groups.iter().map(|g| count_answered_by_everyone(g));
+
+    // then
+    assert_eq!(anyone.collect::<Vec<_>>(), [3, 3, 3, 1, 1]);
+    assert_eq!(everyone.collect::<Vec<_>>(), [3, 0, 1, 1, 1]);
+}
+
+#[test]
+fn rejects_groups_with_other_characters() {
+    // when
+    let error = parse_group("ab\na1").unwrap_err();
+
+    // then
+    assert_eq!((error.line, error.column), (Some(2), Some(2)));
+}
@@ -0,0 +1,153 @@
+//! Bag rules forming a DAG: every bag sits on one of a few layers and only holds bags
+//! from deeper layers. Shiny gold sits in the middle, so some bags hold it and it holds
+//! some bags, while the nesting stays shallow enough for an `i32` count.
+
+use aoc_common::Rng;
+
+const ADJECTIVES: [&str; 18] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+
+const COLORS: [&str; 33] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+
+const LAYERS: usize = 7;
+const GOLD_LAYER: usize = 3;
+
+/// At most one rule per bag name, so `size` is capped at 594.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut names = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLORS
+                .iter()
+                .map(move |color| format!("{} {}", adjective, color))
+        })
+        .filter(|name| name != "shiny gold")
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(3, names.len() + 1) - 1);
+    names.insert(0, "shiny gold".to_owned());
+
+    // Shiny gold, one bag above it and one below it are placed first, the rest anywhere.
+    let mut layers = vec![vec![]; LAYERS];
+    for (index, name) in names.iter().enumerate() {
+        let layer = match index {
+            0 => GOLD_LAYER,
+            1 => GOLD_LAYER - 1,
+            2 => GOLD_LAYER + 1,
+            _ => rng.index(LAYERS),
+        };
+        layers[layer].push(name.as_str());
+    }
+
+    let mut rules = vec![];
+    for layer in 0..LAYERS {
+        let deeper = layers[layer + 1..].concat();
+        for (index, name) in layers[layer].iter().enumerate() {
+            let mut contents = if deeper.is_empty() {
+                vec![]
+            } else {
+                (0..rng.range(0..=3))
+                    .map(|_| *rng.pick(&deeper))
+                    .collect::<Vec<_>>()
+            };
+            if layer == GOLD_LAYER - 1 && index == 0 {
+                contents.push("shiny gold");
+            }
+            if *name == "shiny gold" {
+                contents.push(layers[GOLD_LAYER + 1][0]);
+            }
+            contents.sort_unstable();
+            contents.dedup();
+
+            rules.push(rule(rng, name, &contents));
+        }
+    }
+
+    rng.shuffle(&mut rules);
+    rules.concat()
+}
+
+fn rule(rng: &mut Rng, name: &str, contents: &[&str]) -> String {
+    if contents.is_empty() {
+        return format!("{} bags contain no other bags.\n", name);
+    }
+
+    let contents = contents
+        .iter()
+        .map(|bag| match rng.range(1..=5) {
+            1 => format!("1 {} bag", bag),
+            amount => format!("{} {} bags", amount, bag),
+        })
+        .collect::<Vec<_>>();
+    format!("{} bags contain {}.\n", name, contents.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HandyHaversacks;
+    use aoc_common::Solution;
+
+    #[test]
+    fn generates_bags_around_shiny_gold() {
+        for seed in 0..10 {
+            // given
+            let input = input(&mut Rng::new(seed), 200);
+
+            // when
+            let rules = HandyHaversacks::parse(&input).unwrap();
+            let holding_gold = HandyHaversacks::part1(&rules).unwrap();
+            let inside_gold = HandyHaversacks::part2(&rules).unwrap();
+
+            // then
+            assert_eq!(input.lines().count(), 200);
+            assert!(holding_gold > 0, "seed {}", seed);
+            assert!(inside_gold > 0, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn caps_size_at_the_number_of_bag_names() {
+        // when
+        let input = input(&mut Rng::new(0), 10_000);
+
+        // then
+        assert_eq!(input.lines().count(), 594);
+    }
+}
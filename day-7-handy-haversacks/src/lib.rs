@@ -0,0 +1,509 @@
+//! [Day 7: Handy Haversacks](https://adventofcode.com/2020/day/7): follow the luggage rules
+//! to find which bags can end up holding a shiny gold bag, and how many bags it holds.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+mod generate;
+
+/// One line of the rules, `<color> bags contain <n> <color> bags, ...`, parsed with
+/// [`str::parse`].
+#[derive(Debug)]
+pub struct Rule {
+    bag_type: String,
+    can_contain: Vec<(String, i32)>,
+}
+
+impl Rule {
+    /// Color of the bag the rule is about.
+    pub fn bag_type(&self) -> &str {
+        &self.bag_type
+    }
+
+    /// Colors and amounts of the bags it must directly contain.
+    pub fn contents(&self) -> &[(String, i32)] {
+        &self.can_contain
+    }
+
+    /// Whether a `bag` ends up somewhere inside this one, following `rules` for the bags
+    /// within. Rules that lead back to themselves are only followed once.
+    pub fn can_hold(&self, bag: &str, rules: &[Rule]) -> bool {
+        self.can_hold_unseen(bag, rules, &mut HashSet::new())
+    }
+
+    /// Skips rules already looked at, so cyclic rules cannot recurse forever.
+    fn can_hold_unseen<'a>(
+        &'a self,
+        bag: &str,
+        rules: &'a [Rule],
+        seen: &mut HashSet<&'a str>,
+    ) -> bool {
+        if self.can_contain.is_empty() || !seen.insert(&self.bag_type) {
+            return false;
+        }
+
+        if self.can_contain.iter().any(|(bag_type, _)| bag_type == bag) {
+            // println!("matched rule for ({:?})", self);
+            return true;
+        }
+
+        self.can_contain
+            .iter()
+            .filter_map(|(bag_type, _)| rules.iter().find(|r| &r.bag_type == bag_type))
+            .any(|rule| rule.can_hold_unseen(bag, rules, seen))
+    }
+
+    /// All the bags inside this one, at any depth. Fails when a bag has no rule in `rules`,
+    /// ends up inside itself, or the count does not fit an `i32`.
+    pub fn count_nested_bags(&self, rules: &[Rule]) -> Result<i32> {
+        self.count_nested_bags_along(rules, &mut vec![])
+    }
+
+    /// `path` holds the bags being counted, so a bag that ends up inside itself is an error.
+    fn count_nested_bags_along<'a>(
+        &'a self,
+        rules: &'a [Rule],
+        path: &mut Vec<&'a str>,
+    ) -> Result<i32> {
+        if path.contains(&self.bag_type.as_str()) {
+            return Err(Error::new(
+                HandyHaversacks::DAY,
+                format!("{} bags end up containing themselves", self.bag_type),
+            ));
+        }
+
+        path.push(&self.bag_type);
+        let count = self
+            .can_contain
+            .iter()
+            .try_fold(0i32, |count, (bag_type, amount)| {
+                let nested = find_rule(rules, bag_type)?.count_nested_bags_along(rules, path)?;
+                amount
+                    .checked_mul(nested + 1)
+                    .and_then(|bags| count.checked_add(bags))
+                    .ok_or_else(|| Error::new(HandyHaversacks::DAY, "too many bags to count"))
+            });
+        path.pop();
+
+        count
+    }
+}
+
+fn find_rule<'a>(rules: &'a [Rule], bag: &str) -> Result<&'a Rule> {
+    rules
+        .iter()
+        .find(|r| r.bag_type == bag)
+        .ok_or_else(|| Error::new(HandyHaversacks::DAY, format!("no rule for {} bags", bag)))
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        lazy_static! {
+            static ref BAG_REGEX: Regex = Regex::new(r"(^|\d+)\s?(.+?)(?:\s+bags?)").unwrap();
+        }
+
+        let mut captures = BAG_REGEX.captures_iter(s);
+        let bag_type = match captures.next() {
+            Some(cap) if cap[1].is_empty() && s[cap[0].len()..].starts_with(" contain") => {
+                cap[2].trim().to_owned()
+            }
+            _ => {
+                return Err(Error::parse(
+                    HandyHaversacks::DAY,
+                    s,
+                    0,
+                    "expected `<color> bags contain ...`",
+                ))
+            }
+        };
+
+        let can_contain = captures
+            .map(|cap| {
+                let amount = cap.get(1).unwrap();
+                amount
+                    .as_str()
+                    .parse::<i32>()
+                    .map(|amount| (cap[2].to_owned(), amount))
+                    .map_err(|e| {
+                        Error::parse(
+                            HandyHaversacks::DAY,
+                            s,
+                            amount.start(),
+                            format!("invalid amount of bags ({})", e),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<(String, i32)>>>()?;
+
+        Ok(Rule {
+            bag_type,
+            can_contain,
+        })
+    }
+}
+
+/// Every rule, collected from an iterator of [`Rule`]s.
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    /// How many colors of bag can end up holding a `bag`.
+    pub fn how_many_can_hold(&self, bag: &str) -> usize {
+        self.rules
+            .iter()
+            // .inspect(|r| println!("Base rules: {:?}", r))
+            .filter(|r| r.can_hold(bag, &self.rules))
+            .count()
+    }
+
+    /// All the bags inside a `bag`, as in [`Rule::count_nested_bags`].
+    pub fn count_nested_bags_for(&self, bag: &str) -> Result<i32> {
+        let root_rule = find_rule(&self.rules, bag)?;
+
+        root_rule.count_nested_bags(&self.rules)
+    }
+}
+
+impl FromIterator<Rule> for RuleSet {
+    fn from_iter<T: IntoIterator<Item = Rule>>(iter: T) -> Self {
+        RuleSet {
+            rules: Vec::from_iter(iter),
+        }
+    }
+}
+
+/// The puzzle as a [`Solution`]: the bags that can hold a shiny gold bag, then the bags
+/// inside it.
+pub struct HandyHaversacks;
+
+impl Solution for HandyHaversacks {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Handy Haversacks";
+
+    type Input = RuleSet;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_lines(input, str::parse).map(|rules| rules.into_iter().collect())
+    }
+
+    fn part1(rules: &Self::Input) -> Result<Self::Answer1> {
+        Ok(rules.how_many_can_hold("shiny gold"))
+    }
+
+    fn part2(rules: &Self::Input) -> Result<Self::Answer2> {
+        rules.count_nested_bags_for("shiny gold")
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_count_when_the_bag_is_on_the_left_side() {
+        // given
+        let example_rules = "
+        shiny gold bags contain 1 bright white bag, 2 dark olive bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let can_hold_shiny_gold_amount = example_rules.how_many_can_hold("shiny gold");
+
+        // then
+        assert_eq!(can_hold_shiny_gold_amount, 0)
+    }
+
+    #[test]
+    fn should_count_when_the_bag_is_on_the_right_side() {
+        // given
+        let example_rules = "
+            dark olive bags contain 1 bright white bag, 2 shiny gold bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let can_hold_shiny_gold_amount = example_rules.how_many_can_hold("shiny gold");
+
+        // then
+        assert_eq!(can_hold_shiny_gold_amount, 1)
+    }
+
+    #[test]
+    fn should_count_when_the_bag_is_nested() {
+        // given
+        let example_rules = "
+            dark olive bags contain 1 bright white bag, 2 light red bags.
+            light red bags contain 2 shiny gold bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let can_hold_shiny_gold_amount = example_rules.how_many_can_hold("shiny gold");
+
+        // then
+        assert_eq!(can_hold_shiny_gold_amount, 2)
+    }
+
+    #[test]
+    fn part1_example() {
+        // given
+        let example_rules = "
+            light red bags contain 1 bright white bag, 2 muted yellow bags.
+            dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+            bright white bags contain 1 shiny gold bag.
+            muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+            shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+            dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+            vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+            faded blue bags contain no other bags.
+            dotted black bags contain no other bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let can_hold_shiny_gold_amount = example_rules.how_many_can_hold("shiny gold");
+
+        // then
+        assert_eq!(can_hold_shiny_gold_amount, 4);
+    }
+
+    #[test]
+    fn part2_example_1() {
+        // given
+        let example_rules = "
+            light red bags contain 1 bright white bag, 2 muted yellow bags.
+            dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+            bright white bags contain 1 shiny gold bag.
+            muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+            shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+            dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+            vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+            faded blue bags contain no other bags.
+            dotted black bags contain no other bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let nested_bags = example_rules.count_nested_bags_for("shiny gold").unwrap();
+
+        // then
+        assert_eq!(nested_bags, 32)
+    }
+
+    #[test]
+    fn can_count_bags_for_simple_case() {
+        // given
+        let example_rules = "
+            light red bags contain 1 bright white bag, 2 muted yellow bags.
+            dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+            bright white bags contain 1 shiny gold bag.
+            muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+            shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+            dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+            vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+            faded blue bags contain no other bags.
+            dotted black bags contain no other bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let nested_bags = example_rules.count_nested_bags_for("dark olive").unwrap();
+
+        // then
+        // (3 + 4)
+        assert_eq!(nested_bags, 7)
+    }
+
+    #[test]
+    fn can_count_bags_for_multiplied_branches() {
+        // given
+        let example_rules = "
+            vibrant plum bags contain 2 dark olive bags.
+            dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+            faded blue bags contain 5 dotted black bags.
+            dotted black bags contain no other bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let nested_bags = example_rules.count_nested_bags_for("vibrant plum").unwrap();
+
+        // then
+        // 2 + 2*(3 + (3 * 5) + 4)
+        assert_eq!(nested_bags, 46)
+    }
+
+    #[test]
+    fn part2_example_2() {
+        // given
+        let example_rules = "
+            shiny gold bags contain 2 dark red bags.
+            dark red bags contain 2 dark orange bags.
+            dark orange bags contain 2 dark yellow bags.
+            dark yellow bags contain 2 dark green bags.
+            dark green bags contain 2 dark blue bags.
+            dark blue bags contain 2 dark violet bags.
+            dark violet bags contain no other bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let nested_bags = example_rules.count_nested_bags_for("shiny gold").unwrap();
+
+        // then
+        assert_eq!(nested_bags, 126)
+    }
+
+    #[test]
+    fn reports_rule_without_bag_type() {
+        // when
+        let error = "contain 2 shiny gold bags.".parse::<Rule>().unwrap_err();
+
+        // then
+        assert_eq!(error.column, Some(1));
+    }
+
+    #[test]
+    fn reports_missing_nested_rule() {
+        // given
+        let example_rules = "shiny gold bags contain 2 dark red bags."
+            .parse::<Rule>()
+            .into_iter()
+            .collect::<RuleSet>();
+
+        // when
+        let error = example_rules
+            .count_nested_bags_for("shiny gold")
+            .unwrap_err();
+
+        // then
+        assert_eq!(error.message, "no rule for dark red bags");
+    }
+
+    #[test]
+    fn reads_amounts_with_several_digits() {
+        // given
+        let example_rules = "
+            shiny gold bags contain 12 dark red bags.
+            dark red bags contain no other bags.
+        "
+        .trim()
+        .lines()
+        .map(|r| r.parse::<Rule>().unwrap())
+        .collect::<RuleSet>();
+
+        // when
+        let nested_bags = example_rules.count_nested_bags_for("shiny gold").unwrap();
+
+        // then
+        assert_eq!(nested_bags, 12)
+    }
+
+    #[test]
+    fn fuzzed_rules_never_panics() {
+        let seeds = aoc_common::lines(include_str!("../data/data.txt")).collect::<Vec<_>>();
+
+        aoc_common::fuzz::fuzz(&seeds, |input| {
+            let _ = input.parse::<Rule>();
+        });
+    }
+
+    #[test]
+    fn counts_holders_despite_cyclic_rules() {
+        // given
+        let rules = "
+        light red bags contain 1 dark orange bag.
+        dark orange bags contain 2 light red bags, 1 shiny gold bag.
+        bright white bags contain 3 muted yellow bags.
+        muted yellow bags contain 1 bright white bag.
+        shiny gold bags contain no other bags.
+        ";
+
+        // when
+        let holders = HandyHaversacks::part1(&HandyHaversacks::parse(rules).unwrap()).unwrap();
+
+        // then
+        assert_eq!(holders, 2);
+    }
+
+    #[test]
+    fn reports_bags_containing_themselves() {
+        // given
+        let rules = "
+        shiny gold bags contain 1 dark olive bag.
+        dark olive bags contain 2 vibrant plum bags.
+        vibrant plum bags contain 1 shiny gold bag.
+        ";
+
+        // when
+        let error = HandyHaversacks::part2(&HandyHaversacks::parse(rules).unwrap()).unwrap_err();
+
+        // then
+        assert!(
+            error
+                .to_string()
+                .contains("shiny gold bags end up containing themselves"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn reports_too_many_bags_instead_of_overflowing() {
+        // given
+        let rules = (0..12)
+            .map(|bag| format!("bag{} x bags contain 9999 bag{} x bags.", bag, bag + 1))
+            .chain(std::iter::once(
+                "bag12 x bags contain no other bags.".to_owned(),
+            ))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // when
+        let error = HandyHaversacks::parse(&rules)
+            .unwrap()
+            .count_nested_bags_for("bag0 x")
+            .unwrap_err();
+
+        // then
+        assert!(error.to_string().contains("too many bags"), "{}", error);
+    }
+}
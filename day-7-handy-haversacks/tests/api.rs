@@ -0,0 +1,49 @@
+use day_7_handy_haversacks::{Rule, RuleSet};
+
+const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+fn rules() -> Vec<Rule> {
+    EXAMPLE.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+#[test]
+fn reads_rules() {
+    // when
+    let rules = rules();
+
+    // then
+    assert_eq!(rules[0].bag_type(), "light red");
+    assert_eq!(
+        rules[0].contents(),
+        [
+            ("bright white".to_owned(), 1),
+            ("muted yellow".to_owned(), 2)
+        ]
+    );
+    assert!(rules[7].contents().is_empty());
+}
+
+#[test]
+fn follows_rules_both_ways() {
+    // given
+    let rules = rules();
+
+    // then
+    assert!(rules[0].can_hold("shiny gold", &rules));
+    assert!(!rules[4].can_hold("shiny gold", &rules));
+    assert_eq!(rules[4].count_nested_bags(&rules).unwrap(), 32);
+
+    let set = rules.into_iter().collect::<RuleSet>();
+    assert_eq!(set.how_many_can_hold("shiny gold"), 4);
+    assert_eq!(set.count_nested_bags_for("shiny gold").unwrap(), 32);
+    assert!(set.count_nested_bags_for("plaid magenta").is_err());
+}
@@ -0,0 +1,74 @@
+//! Boot code that loops forever unless exactly one instruction is changed.
+//!
+//! A single `jmp` at `looping` jumps back. Before it, every `jmp` lands at most on
+//! `looping` and every `nop` points backwards, so whatever else is flipped, execution
+//! still reaches the looping `jmp`. After it, every `jmp` goes forward, so turning that
+//! one `jmp` into a `nop` runs straight to the end.
+
+use aoc_common::Rng;
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let length = size.clamp(4, 10_000) as i64;
+    let looping = rng.range(length / 4..=length * 3 / 4);
+    let mut accumulator = 0;
+
+    (0..length)
+        .map(|index| {
+            let (operation, argument) = if index == looping {
+                ("jmp", -rng.range(1..=looping.min(20)))
+            } else {
+                let last = if index < looping { looping } else { length };
+                match rng.below(4) {
+                    0 => ("jmp", rng.range(1..=(last - index).min(5))),
+                    1 if index < looping => ("nop", -rng.range(0..=index.min(20))),
+                    1 => ("nop", rng.range(-20..=20)),
+                    _ => {
+                        // Pulls the accumulator back towards zero to keep the answers small.
+                        let value = rng.range(1..=20);
+                        let value = if accumulator > 0 { -value } else { value };
+                        accumulator += value;
+                        ("acc", value)
+                    }
+                }
+            };
+
+            format!("{} {:+}\n", operation, argument)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_instruction, run, Operation};
+
+    #[test]
+    fn exactly_one_change_terminates() {
+        for seed in 0..10 {
+            // given
+            let input = input(&mut Rng::new(seed), 300);
+            let program = input
+                .lines()
+                .map(|line| parse_instruction(line).unwrap())
+                .collect::<Vec<_>>();
+
+            // when
+            let fixes = (0..program.len())
+                .filter(|&index| {
+                    let mut changed = program.clone();
+                    changed[index] = match program[index] {
+                        Operation::Jmp(v) => Operation::Nop(v),
+                        Operation::Nop(v) => Operation::Jmp(v),
+                        Operation::Acc(_) => return false,
+                    };
+                    run(&changed).0
+                })
+                .count();
+
+            // then
+            assert_eq!(program.len(), 300);
+            assert!(!run(&program).0, "seed {}", seed);
+            assert_eq!(fixes, 1, "seed {}", seed);
+        }
+    }
+}
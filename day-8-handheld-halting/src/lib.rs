@@ -0,0 +1,297 @@
+//! [Day 8: Handheld Halting](https://adventofcode.com/2020/day/8): run the boot code until it
+//! loops, then find the one `jmp` or `nop` to swap so that it terminates.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+mod generate;
+
+/// Registers of the handheld console.
+///
+/// They are wider than the arguments: every instruction runs at most once, so the
+/// accumulator stays within `i16::MAX` times the length of the program.
+#[derive(Debug, Default)]
+pub struct State {
+    /// The only global value, changed by `acc`.
+    pub accumulator: i64,
+    /// Index of the next instruction to run.
+    pub instruction: i64,
+}
+
+impl State {
+    /// The state at boot, before the first instruction.
+    pub fn new() -> State {
+        State {
+            accumulator: 0,
+            instruction: 0,
+        }
+    }
+}
+
+/// One instruction of the boot code, with its signed argument.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Operation {
+    /// Adds the argument to the accumulator.
+    Acc(i16),
+    /// Does nothing, the argument included.
+    Nop(i16),
+    /// Jumps by the argument, relative to itself.
+    Jmp(i16),
+}
+
+impl Operation {
+    /// The state after running this instruction.
+    pub fn apply(&self, state: State) -> State {
+        match self {
+            Operation::Acc(v) => State {
+                accumulator: state.accumulator + i64::from(*v),
+                instruction: state.instruction + 1,
+            },
+            Operation::Nop(_) => State {
+                accumulator: state.accumulator,
+                instruction: state.instruction + 1,
+            },
+            Operation::Jmp(v) => State {
+                accumulator: state.accumulator,
+                instruction: state.instruction + i64::from(*v),
+            },
+        }
+    }
+}
+
+/// Reads a line like `acc +3` or `jmp -4`.
+pub fn parse_instruction(s: &str) -> Result<Operation> {
+    let mut parts = s.split_whitespace();
+    let offset_of = |part: &str| part.as_ptr() as usize - s.as_ptr() as usize;
+
+    let instruction = parts.next().unwrap_or_default();
+    let argument = parts.next().ok_or_else(|| {
+        Error::parse(
+            HandheldHalting::DAY,
+            s,
+            s.trim_end().len(),
+            "expected an argument",
+        )
+    })?;
+    let val = argument.parse::<i16>().map_err(|e| {
+        Error::parse(
+            HandheldHalting::DAY,
+            s,
+            offset_of(argument),
+            format!("invalid argument ({})", e),
+        )
+    })?;
+
+    if let Some(extra) = parts.next() {
+        return Err(Error::parse(
+            HandheldHalting::DAY,
+            s,
+            offset_of(extra),
+            "unexpected text after the argument",
+        ));
+    }
+
+    match instruction {
+        "acc" => Ok(Operation::Acc(val)),
+        "nop" => Ok(Operation::Nop(val)),
+        "jmp" => Ok(Operation::Jmp(val)),
+        _ => Err(Error::parse(
+            HandheldHalting::DAY,
+            s,
+            offset_of(instruction),
+            "expected `acc`, `jmp` or `nop`",
+        )),
+    }
+}
+
+/// Final state of the first program that terminates when a single `jmp` is turned into a
+/// `nop`, or a `nop` into a `jmp`.
+pub fn repair(instructions: &[Operation]) -> Option<State> {
+    let mods = instructions.iter().enumerate().filter(|(_, op)| {
+        if let Operation::Jmp(_) = op {
+            return true;
+        }
+        if let Operation::Nop(_) = op {
+            return true;
+        }
+        false
+    });
+
+    for (mod_index, mod_op) in mods {
+        let mut mod_instructions = instructions.to_vec();
+
+        mod_instructions[mod_index] = match mod_op {
+            Operation::Jmp(v) => Operation::Nop(v.to_owned()),
+            Operation::Nop(v) => Operation::Jmp(v.to_owned()),
+            _ => unreachable!(),
+        };
+
+        let (res, last_state) = run(&mod_instructions);
+
+        if res {
+            return Some(last_state);
+        }
+    }
+
+    None
+}
+
+/// The puzzle as a [`Solution`]: the accumulator just before the loop, then after the
+/// repaired program terminates.
+pub struct HandheldHalting;
+
+impl Solution for HandheldHalting {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Handheld Halting";
+
+    type Input = Vec<Operation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_lines(input, parse_instruction)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Self::Answer1> {
+        let (_, state) = run(instructions);
+
+        Ok(state.accumulator)
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Self::Answer2> {
+        repair(instructions)
+            .map(|state| state.accumulator)
+            .ok_or_else(|| Error::new(Self::DAY, "no single change makes the program terminate"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Runs until the program terminates, jumps past its end or is about to run an instruction a
+/// second time. Whether it terminated comes with the state it stopped in.
+pub fn run(instructions: &[Operation]) -> (bool, State) {
+    let mut state = State::new();
+    let mut seen = HashSet::<usize>::new();
+
+    loop {
+        let index = match usize::try_from(state.instruction) {
+            Ok(index) if index == instructions.len() => return (true, state),
+            Ok(index) if index < instructions.len() => index,
+            _ => return (false, state),
+        };
+
+        if seen.contains(&index) {
+            return (false, state);
+        }
+
+        seen.insert(index);
+
+        let instruction = instructions.get(index).unwrap();
+        state = instruction.apply(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "
+        nop +0
+        acc +1
+        jmp +4
+        acc +3
+        jmp -3
+        acc -99
+        acc +1
+        jmp -4
+        acc +6
+    ";
+
+    #[test]
+    fn part1_example() {
+        // given
+        let instructions = HandheldHalting::parse(EXAMPLE).unwrap();
+
+        // when
+        let accumulator = HandheldHalting::part1(&instructions).unwrap();
+
+        // then
+        assert_eq!(accumulator, 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        // given
+        let instructions = HandheldHalting::parse(EXAMPLE).unwrap();
+
+        // when
+        let accumulator = HandheldHalting::part2(&instructions).unwrap();
+
+        // then
+        assert_eq!(accumulator, 8);
+    }
+
+    #[test]
+    fn reports_unknown_operation() {
+        // when
+        let error = HandheldHalting::parse("nop +0\nmul +2\n").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(1));
+        assert_eq!(error.text, "mul +2");
+    }
+
+    #[test]
+    fn reports_invalid_argument() {
+        // when
+        let error = parse_instruction("acc +x").unwrap_err();
+
+        // then
+        assert_eq!(error.column, Some(5));
+    }
+
+    #[test]
+    fn reports_missing_argument() {
+        // when
+        let error = parse_instruction("jmp").unwrap_err();
+
+        // then
+        assert_eq!(error.column, Some(4));
+    }
+
+    #[test]
+    fn fuzzed_instructions_never_panics() {
+        let seeds = aoc_common::lines(include_str!("../data/data.txt")).collect::<Vec<_>>();
+
+        aoc_common::fuzz::fuzz(&seeds, |input| {
+            let _ = parse_instruction(input);
+        });
+    }
+
+    #[test]
+    fn reports_text_after_the_argument() {
+        // when
+        let error = HandheldHalting::parse("nop +0\nacc +1 garbage\n").unwrap_err();
+
+        // then
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(8));
+    }
+
+    #[test]
+    fn accumulates_past_the_range_of_an_argument() {
+        // given
+        let instructions = HandheldHalting::parse("acc +32767\nacc +1\njmp -2\n").unwrap();
+
+        // when
+        let accumulator = HandheldHalting::part1(&instructions).unwrap();
+
+        // then
+        assert_eq!(accumulator, 32768);
+    }
+}
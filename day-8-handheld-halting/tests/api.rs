@@ -0,0 +1,46 @@
+use day_8_handheld_halting::{parse_instruction, repair, run, Operation, State};
+
+const EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+fn program() -> Vec<Operation> {
+    EXAMPLE
+        .lines()
+        .map(|line| parse_instruction(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn applies_single_operations() {
+    // when
+    let state = Operation::Jmp(-3).apply(Operation::Acc(5).apply(State::new()));
+
+    // then
+    assert_eq!((state.accumulator, state.instruction), (5, -2));
+    assert_eq!(parse_instruction("nop -7").unwrap(), Operation::Nop(-7));
+    assert!(parse_instruction("hop +1").is_err());
+}
+
+#[test]
+fn stops_before_the_loop_and_repairs_it() {
+    // given
+    let program = program();
+
+    // when
+    let (terminated, looped) = run(&program);
+    let repaired = repair(&program).unwrap();
+
+    // then
+    assert!(!terminated);
+    assert_eq!(looped.accumulator, 5);
+    assert_eq!(repaired.accumulator, 8);
+    assert_eq!(repaired.instruction, 9);
+}
@@ -0,0 +1,98 @@
+//! XMAS streams where every number after the preamble is the sum of two of the previous
+//! 25, except a single invalid one, which is the sum of a contiguous run before it.
+//!
+//! Sums are built from the smallest numbers of the window to keep the stream growing
+//! slowly, which still caps it at 1000 numbers to stay within an `i64`.
+
+use crate::PREAMBLE;
+use aoc_common::Rng;
+use itertools::Itertools;
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let length = size.clamp(PREAMBLE + 2, 1000);
+    let invalid_at = rng.range((PREAMBLE + 1) as i64..=(length - 1) as i64) as usize;
+
+    let mut numbers = (0..PREAMBLE)
+        .map(|_| rng.range(1..=50))
+        .collect::<Vec<i64>>();
+
+    while numbers.len() < length {
+        let window = &numbers[numbers.len() - PREAMBLE..];
+        let next = if numbers.len() == invalid_at {
+            invalid(rng, &numbers, window)
+        } else {
+            let mut smallest = (0..PREAMBLE).collect::<Vec<_>>();
+            smallest.sort_by_key(|&i| window[i]);
+            smallest.truncate(8);
+
+            let first = *rng.pick(&smallest);
+            let second = *rng.pick(&smallest);
+            if first == second {
+                continue;
+            }
+            window[first] + window[second]
+        };
+        numbers.push(next);
+    }
+
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+/// Sum of a contiguous run of two or more earlier numbers that no two numbers of
+/// the window add up to.
+fn invalid(rng: &mut Rng, numbers: &[i64], window: &[i64]) -> i64 {
+    loop {
+        let start = rng.index(numbers.len() - 1);
+        let end = rng.range(start as i64 + 2..=(start + 17).min(numbers.len()) as i64) as usize;
+        let sum = numbers[start..end].iter().sum::<i64>();
+
+        if !window
+            .iter()
+            .tuple_combinations()
+            .any(|(a, b)| a + b == sum)
+        {
+            return sum;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EncodingError;
+    use aoc_common::Solution;
+
+    #[test]
+    fn plants_one_invalid_number_with_a_contiguous_set() {
+        for seed in 0..10 {
+            // given
+            let input = input(&mut Rng::new(seed), 500);
+            let numbers = EncodingError::parse(&input).unwrap();
+
+            // when
+            let invalid = (PREAMBLE..numbers.len())
+                .filter(|&i| {
+                    !numbers[i - PREAMBLE..i]
+                        .iter()
+                        .tuple_combinations()
+                        .any(|(a, b)| a + b == numbers[i])
+                })
+                .count();
+
+            // then
+            assert_eq!(numbers.len(), 500);
+            assert_eq!(invalid, 1, "seed {}", seed);
+            assert!(EncodingError::part2(&numbers).is_ok(), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn stays_within_i64_at_the_largest_size() {
+        // when
+        let input = input(&mut Rng::new(0), usize::MAX);
+
+        // then
+        assert_eq!(input.lines().count(), 1000);
+        assert!(EncodingError::parse(&input).is_ok());
+    }
+}
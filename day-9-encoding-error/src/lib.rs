@@ -0,0 +1,219 @@
+//! [Day 9: Encoding Error](https://adventofcode.com/2020/day/9): find the first number of
+//! the XMAS stream that breaks its rule, then the contiguous run that sums to it.
+#![warn(missing_docs)]
+
+use aoc_common::{Error, Result, Rng, Solution};
+#[cfg(test)]
+use itertools::Itertools;
+use std::collections::HashMap;
+
+mod generate;
+
+/// The puzzle as a [`Solution`]: the invalid number, then the sum of the smallest and largest
+/// numbers of the run adding up to it.
+pub struct EncodingError;
+
+/// How many previous numbers each one must be a sum of two of.
+pub const PREAMBLE: usize = 25;
+
+impl Solution for EncodingError {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Encoding Error";
+
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::parse_lines(input, |line| aoc_common::number(Self::DAY, line))
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Self::Answer1> {
+        find_invalid_number(numbers, PREAMBLE).ok_or_else(|| {
+            Error::new(
+                Self::DAY,
+                format!("every number is a sum of two of the previous {}", PREAMBLE),
+            )
+        })
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Self::Answer2> {
+        let invalid_number = Self::part1(numbers)?;
+        let set = find_contiguous_set(numbers, invalid_number).ok_or_else(|| {
+            Error::new(
+                Self::DAY,
+                format!("no contiguous set sums to {}", invalid_number),
+            )
+        })?;
+
+        Ok(set.iter().min().unwrap() + set.iter().max().unwrap())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// First number that is not the sum of two of the `preamble` numbers before it,
+/// keeping a count of each value in the window as it slides along.
+pub fn find_invalid_number(numbers: &[i64], preamble: usize) -> Option<i64> {
+    let mut window = HashMap::<i64, usize>::new();
+    for &number in numbers.iter().take(preamble) {
+        *window.entry(number).or_default() += 1;
+    }
+
+    for index in preamble..numbers.len() {
+        let number = numbers[index];
+        let is_sum = window.iter().any(|(&value, &count)| {
+            let other = number - value;
+            if other == value {
+                count >= 2
+            } else {
+                window.contains_key(&other)
+            }
+        });
+
+        if !is_sum {
+            return Some(number);
+        }
+
+        if preamble > 0 {
+            let leaving = numbers[index - preamble];
+            let count = window.get_mut(&leaving).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                window.remove(&leaving);
+            }
+            *window.entry(number).or_default() += 1;
+        }
+    }
+
+    None
+}
+
+/// Brute-force reference for [`find_invalid_number`], trying every pair of the window.
+#[cfg(test)]
+fn find_invalid_number_by_pairs(numbers: &[i64], preamble: usize) -> Option<i64> {
+    for number_index in preamble..numbers.len() {
+        let number = numbers.get(number_index).unwrap();
+        let prev_batch = &numbers[number_index - preamble..number_index];
+
+        if !prev_batch
+            .iter()
+            .combinations(2)
+            .map(|p| p.iter().map(|v| v.to_owned()).sum::<i64>())
+            .any(|s| s == *number)
+        {
+            return Some(number.to_owned());
+        }
+    }
+
+    None
+}
+
+/// First run of consecutive numbers that sums to `target`.
+pub fn find_contiguous_set(numbers: &[i64], target: i64) -> Option<&[i64]> {
+    let mut start = 0;
+    let mut end = 1;
+
+    while end <= numbers.len() {
+        let s = &numbers[start..end];
+        let sum = s.iter().sum::<i64>();
+
+        if sum < target {
+            end += 1
+        }
+        if sum > target {
+            start += 1;
+            end = start + 1;
+        }
+
+        if sum == target {
+            return Some(s);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [i64; 20] = [
+        35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309, 576,
+    ];
+
+    #[test]
+    fn part1_example() {
+        // when
+        let invalid_number = find_invalid_number(&EXAMPLE, 5);
+
+        // then
+        assert_eq!(invalid_number, Some(127));
+    }
+
+    #[test]
+    fn part2_example() {
+        // when
+        let set = find_contiguous_set(&EXAMPLE, 127);
+
+        // then
+        assert_eq!(set, Some(&[15, 25, 47, 40][..]));
+    }
+
+    #[test]
+    fn finds_no_contiguous_set_past_the_end() {
+        // when
+        let set = find_contiguous_set(&[1, 2, 3], 100);
+
+        // then
+        assert_eq!(set, None);
+    }
+
+    #[test]
+    fn reports_missing_invalid_number() {
+        // given
+        let numbers = (1..=30).collect::<Vec<i64>>();
+
+        // when
+        let error = EncodingError::part1(&numbers).unwrap_err();
+
+        // then
+        assert_eq!(
+            error.message,
+            "every number is a sum of two of the previous 25"
+        );
+    }
+
+    #[test]
+    fn sliding_window_matches_every_pair_on_generated_streams() {
+        aoc_common::differential::compare(
+            100,
+            |rng| EncodingError::parse(&generate::input(rng, 200)).unwrap(),
+            |numbers| aoc_common::differential::shrink_vec(numbers),
+            |numbers| find_invalid_number_by_pairs(numbers, PREAMBLE),
+            |numbers| find_invalid_number(numbers, PREAMBLE),
+        );
+    }
+
+    #[test]
+    fn sliding_window_matches_every_pair_with_repeated_numbers() {
+        aoc_common::differential::compare(
+            500,
+            |rng| {
+                let preamble = rng.range(0..=5) as usize;
+                let numbers = (0..30).map(|_| rng.range(-5..=10)).collect::<Vec<i64>>();
+                (preamble, numbers)
+            },
+            |(preamble, numbers)| {
+                aoc_common::differential::shrink_vec(numbers)
+                    .into_iter()
+                    .map(|numbers| (*preamble, numbers))
+                    .collect()
+            },
+            |(preamble, numbers)| find_invalid_number_by_pairs(numbers, *preamble),
+            |(preamble, numbers)| find_invalid_number(numbers, *preamble),
+        );
+    }
+}
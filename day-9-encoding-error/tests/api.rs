@@ -0,0 +1,32 @@
+use aoc_common::Solution;
+use day_9_encoding_error::{find_contiguous_set, find_invalid_number, EncodingError, PREAMBLE};
+
+const EXAMPLE: [i64; 20] = [
+    35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309, 576,
+];
+
+#[test]
+fn finds_the_weakness_with_a_short_preamble() {
+    // when
+    let invalid = find_invalid_number(&EXAMPLE, 5).unwrap();
+    let set = find_contiguous_set(&EXAMPLE, invalid).unwrap();
+
+    // then
+    assert_eq!(invalid, 127);
+    assert_eq!(set, [15, 25, 47, 40]);
+}
+
+#[test]
+fn solves_with_the_real_preamble() {
+    // given
+    let mut numbers = (1..=PREAMBLE as i64).collect::<Vec<_>>();
+    numbers.extend_from_slice(&[26, 49, 100]);
+
+    // when
+    let invalid = EncodingError::part1(&numbers).unwrap();
+    let weakness = EncodingError::part2(&numbers).unwrap();
+
+    // then
+    assert_eq!(invalid, 100);
+    assert_eq!(weakness, 9 + 16);
+}